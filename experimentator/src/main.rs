@@ -7,13 +7,17 @@ use knapsack_library::algorithms_service::AlgorithmsService;
 use metrics_service::bencher::Bencher;
 
 fn main() {
-    let config_path = "experiment.json";
+    // Another configuration, e.g. experiment_parallel.json, can be passed as the first argument
+    let config_path = std::env::args().nth(1).unwrap_or_else(|| "experiment.json".to_string());
     let os_string = "out";
 
     let bencher = Bencher::new(Some(os_string), true).unwrap();
 
     let (knapsacks, algorithms_names) =
-        generate_rnd_knapsacks(config_path).expect("Failed to create knapsack");
-    let algorithms = AlgorithmsService::get_algorithms_by_names(algorithms_names);
+        generate_rnd_knapsacks(&config_path).expect("Failed to create knapsack");
+    let algorithms = AlgorithmsService::get_algorithms_by_names(algorithms_names).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    });
     bencher.conduct_experiment(&algorithms, &knapsacks, os_string);
 }
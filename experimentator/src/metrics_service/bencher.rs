@@ -2,9 +2,12 @@ use crate::data;
 use crate::metrics_service::models::measurement::Measurement;
 use crate::metrics_service::{data_collector, reporter::Reporter};
 use criterion::{BenchmarkId, Criterion};
-use knapsack_library::models::{knapsack::Knapsack, knapsack_solver::KnapsackSolver};
+use knapsack_library::algorithms_service::AlgorithmsService;
+use knapsack_library::models::{
+    knapsack::Knapsack, knapsack_error::KnapsackError, knapsack_solver::KnapsackSolver,
+};
 use std::fmt::write;
-use std::{collections::HashMap, io, time::Duration};
+use std::{collections::BTreeMap, collections::HashMap, io, time::Duration};
 use std::{env, ffi::OsStr, fs, path::PathBuf};
 
 /// A benchmarking utility for evaluating the performance of different knapsack solvers.
@@ -37,7 +40,7 @@ impl Bencher {
     /// * `measurement_time` - Optional measurement duration.
     pub fn bench_group(
         &self,
-        knapsack_solvers: &[Box<dyn KnapsackSolver>],
+        knapsack_solvers: &[&dyn KnapsackSolver],
         knapsacks: &[Knapsack],
         sample_size: Option<usize>,
         warm_up_time: Option<Duration>,
@@ -59,7 +62,8 @@ impl Bencher {
             .measurement_time(measurement_time.unwrap_or(Self::MEASUREMENT_TIME));
 
         for solver in knapsack_solvers {
-            let bench_name = solver.get_name();
+            // The identifier names the criterion directory, so that results can be mapped back to the solver
+            let bench_name = solver.get_id();
             group.bench_with_input(BenchmarkId::new(&bench_name, ""), &knapsacks, |b, ks| {
                 b.iter(|| {
                     ks.iter().for_each(|k| {
@@ -90,16 +94,49 @@ impl Bencher {
         }
         let knapsack_num_items_config = knapsacks[0].get_items_len();
         let number_of_samples = knapsacks.len();
-        self.bench_group(solvers, knapsacks, None, None, None, None);
-        let measurements = Self::get_stats(solvers, knapsacks);
+        let (supported, skipped) = Self::split_supported(solvers, knapsacks);
+        self.bench_group(&supported, knapsacks, None, None, None, None);
+        let measurements = Self::get_stats(&supported, knapsacks);
         println!("{:?}", measurements);
         self.report_table(number_of_samples, knapsack_num_items_config, &measurements);
+        self.report_skipped(&skipped);
 
         data_collector::get_mean_plots(os_string.to_string());
         data_collector::delete_criterion_dir();
     }
 
-    /// Computes the correctness rates of solvers by comparing their results.
+    /// Splits the solvers into the ones supporting every knapsack and the ones that can't handle some of them.
+    ///
+    /// Unsupported solvers are not run at all, since they could exhaust the memory or never finish.
+    ///
+    /// # Arguments
+    ///
+    /// * `solvers` - A slice of knapsack solvers.
+    /// * `knapsacks` - A slice of knapsack instances.
+    ///
+    /// # Returns
+    ///
+    /// * `(Vec<&dyn KnapsackSolver>, Vec<(String, KnapsackError)>)` - The supported solvers, and the names
+    ///   of the skipped solvers with the first exceeded limit.
+    pub(crate) fn split_supported<'a>(
+        solvers: &'a [Box<dyn KnapsackSolver>],
+        knapsacks: &[Knapsack],
+    ) -> (Vec<&'a dyn KnapsackSolver>, Vec<(String, KnapsackError)>) {
+        let mut supported = Vec::new();
+        let mut skipped = Vec::new();
+
+        for solver in solvers {
+            let capabilities = solver.get_capabilities();
+            match knapsacks.iter().try_for_each(|k| capabilities.check(k)) {
+                Ok(()) => supported.push(solver.as_ref()),
+                Err(error) => skipped.push((solver.get_name(), error)),
+            }
+        }
+
+        (supported, skipped)
+    }
+
+    /// Solves every knapsack with every solver once, so that all metrics are computed from the same results.
     ///
     /// # Arguments
     ///
@@ -108,21 +145,41 @@ impl Bencher {
     ///
     /// # Returns
     ///
+    /// * `Vec<Vec<Result<u64, KnapsackError>>>` - The results of the solvers (inner) for every knapsack (outer).
+    fn collect_results(
+        solvers: &[&dyn KnapsackSolver],
+        knapsacks: &[Knapsack],
+    ) -> Vec<Vec<Result<u64, KnapsackError>>> {
+        knapsacks
+            .iter()
+            .map(|knapsack| solvers.iter().map(|solver| solver.solve(knapsack)).collect())
+            .collect()
+    }
+
+    /// Computes the correctness rates of solvers by comparing their results.
+    ///
+    /// # Arguments
+    ///
+    /// * `solvers` - A slice of knapsack solvers.
+    /// * `results` - The results of the solvers for every knapsack, see `collect_results`.
+    ///
+    /// # Returns
+    ///
     /// * `HashMap<String, f64>` - A mapping of solver names to their correctness percentage.
     fn calculate_correct_rates(
-        solvers: &[Box<dyn KnapsackSolver>],
-        knapsacks: &[Knapsack],
+        solvers: &[&dyn KnapsackSolver],
+        results: &[Vec<Result<u64, KnapsackError>>],
     ) -> HashMap<String, f64> {
         let mut correct_rates = HashMap::new();
 
-        for knapsack in knapsacks {
-            let results: Vec<u64> = solvers
+        for knapsack_results in results {
+            let values: Vec<u64> = knapsack_results
                 .iter()
-                .map(|solver| solver.solve(knapsack).unwrap_or(0))
+                .map(|result| *result.as_ref().unwrap_or(&0))
                 .collect();
 
-            if let Some(&best_result) = results.iter().max() {
-                for (solver, &result) in solvers.iter().zip(results.iter()) {
+            if let Some(&best_result) = values.iter().max() {
+                for (solver, &result) in solvers.iter().zip(values.iter()) {
                     if result == best_result {
                         *correct_rates.entry(solver.get_name()).or_insert(0.0) += 1.0;
                     }
@@ -130,7 +187,7 @@ impl Bencher {
             }
         }
 
-        let number_of_samples = knapsacks.len() as f64;
+        let number_of_samples = results.len() as f64;
         correct_rates
             .iter_mut()
             .for_each(|(_, v)| *v = (*v / number_of_samples) * 100.0);
@@ -138,6 +195,150 @@ impl Bencher {
         correct_rates
     }
 
+    /// Computes the mean relative errors of solvers compared to the best result among them.
+    ///
+    /// # Arguments
+    ///
+    /// * `solvers` - A slice of knapsack solvers.
+    /// * `results` - The results of the solvers for every knapsack, see `collect_results`.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, f64>` - A mapping of solver names to their mean relative error percentage.
+    fn calculate_relative_errors(
+        solvers: &[&dyn KnapsackSolver],
+        results: &[Vec<Result<u64, KnapsackError>>],
+    ) -> HashMap<String, f64> {
+        let mut relative_errors: HashMap<String, f64> = solvers
+            .iter()
+            .map(|solver| (solver.get_name(), 0.0))
+            .collect();
+
+        for knapsack_results in results {
+            let values: Vec<u64> = knapsack_results
+                .iter()
+                .map(|result| *result.as_ref().unwrap_or(&0))
+                .collect();
+
+            // A zero best result means that every solver is exact on this knapsack
+            if let Some(&best_result) = values.iter().max().filter(|&&best| best > 0) {
+                for (solver, &result) in solvers.iter().zip(values.iter()) {
+                    let error = (best_result - result) as f64 / best_result as f64;
+                    *relative_errors.entry(solver.get_name()).or_insert(0.0) += error;
+                }
+            }
+        }
+
+        let number_of_samples = results.len() as f64;
+        relative_errors
+            .iter_mut()
+            .for_each(|(_, v)| *v = (*v / number_of_samples) * 100.0);
+
+        relative_errors
+    }
+
+    /// Computes the mean gaps of solvers to the fractional knapsack upper bound.
+    ///
+    /// Unlike the relative error, the gap doesn't depend on the other solvers: an exact solver
+    /// shows how far the 0/1 optimum is from the bound, and a heuristic can be compared against it.
+    ///
+    /// # Arguments
+    ///
+    /// * `solvers` - A slice of knapsack solvers.
+    /// * `knapsacks` - A slice of knapsack instances.
+    /// * `results` - The results of the solvers for every knapsack, see `collect_results`.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, f64>` - A mapping of solver names to their mean gap percentage.
+    fn calculate_lp_gaps(
+        solvers: &[&dyn KnapsackSolver],
+        knapsacks: &[Knapsack],
+        results: &[Vec<Result<u64, KnapsackError>>],
+    ) -> HashMap<String, f64> {
+        let mut lp_gaps: HashMap<String, f64> = solvers
+            .iter()
+            .map(|solver| (solver.get_name(), 0.0))
+            .collect();
+
+        for (knapsack, knapsack_results) in knapsacks.iter().zip(results) {
+            let Ok(solution) = AlgorithmsService::solve_fractional(knapsack) else {
+                continue;
+            };
+            let bound = solution.get_total_value();
+            if bound <= 0.0 {
+                continue;
+            }
+            for (solver, result) in solvers.iter().zip(knapsack_results) {
+                let result = *result.as_ref().unwrap_or(&0) as f64;
+                *lp_gaps.entry(solver.get_name()).or_insert(0.0) += (bound - result) / bound;
+            }
+        }
+
+        let number_of_samples = knapsacks.len() as f64;
+        lp_gaps
+            .iter_mut()
+            .for_each(|(_, v)| *v = (*v / number_of_samples) * 100.0);
+
+        lp_gaps
+    }
+
+    /// Returns the name of the kind of a solver error, used to group failures in the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error returned by a solver.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The name of the error variant.
+    fn error_kind(error: &KnapsackError) -> &'static str {
+        match error {
+            KnapsackError::UnknownAlgorithm { .. } => "UnknownAlgorithm",
+            KnapsackError::TooManyItems { .. } => "TooManyItems",
+            KnapsackError::CapacityTooLarge => "CapacityTooLarge",
+            KnapsackError::ArithmeticOverflow => "ArithmeticOverflow",
+            KnapsackError::InvalidInstance(_) => "InvalidInstance",
+            KnapsackError::InvalidParameter(_) => "InvalidParameter",
+            KnapsackError::Infeasible => "Infeasible",
+            KnapsackError::Cancelled => "Cancelled",
+        }
+    }
+
+    /// Counts the failed runs of solvers by the kind of the returned error.
+    ///
+    /// # Arguments
+    ///
+    /// * `solvers` - A slice of knapsack solvers.
+    /// * `results` - The results of the solvers for every knapsack, see `collect_results`.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, BTreeMap<String, usize>>` - A mapping of solver names to their failure counts by error kind.
+    fn calculate_failures(
+        solvers: &[&dyn KnapsackSolver],
+        results: &[Vec<Result<u64, KnapsackError>>],
+    ) -> HashMap<String, BTreeMap<String, usize>> {
+        let mut failures: HashMap<String, BTreeMap<String, usize>> = solvers
+            .iter()
+            .map(|solver| (solver.get_name(), BTreeMap::new()))
+            .collect();
+
+        for knapsack_results in results {
+            for (solver, result) in solvers.iter().zip(knapsack_results) {
+                if let Err(error) = result {
+                    *failures
+                        .entry(solver.get_name())
+                        .or_default()
+                        .entry(Self::error_kind(error).to_string())
+                        .or_insert(0) += 1;
+                }
+            }
+        }
+
+        failures
+    }
+
     /// Retrieves statistical measurements for the given solvers.
     ///
     /// # Arguments
@@ -148,20 +349,28 @@ impl Bencher {
     /// # Returns
     ///
     /// * `Vec<Measurement>` - A vector of measurements containing solver statistics.
-    fn get_stats(solvers: &[Box<dyn KnapsackSolver>], knapsacks: &[Knapsack]) -> Vec<Measurement> {
-        let correct_rates = Self::calculate_correct_rates(solvers, knapsacks);
+    fn get_stats(solvers: &[&dyn KnapsackSolver], knapsacks: &[Knapsack]) -> Vec<Measurement> {
+        let results = Self::collect_results(solvers, knapsacks);
+        let correct_rates = Self::calculate_correct_rates(solvers, &results);
+        let relative_errors = Self::calculate_relative_errors(solvers, &results);
+        let lp_gaps = Self::calculate_lp_gaps(solvers, knapsacks, &results);
+        let failures = Self::calculate_failures(solvers, &results);
         let time_stats = data_collector::get_criterion_stats().unwrap_or_default();
 
-        let mut solver_names: Vec<String> = solvers.iter().map(|s| s.get_name()).collect();
-        solver_names.sort();
+        let mut solver_ids: Vec<(String, String)> =
+            solvers.iter().map(|s| (s.get_name(), s.get_id())).collect();
+        solver_ids.sort();
 
-        solver_names
+        solver_ids
             .into_iter()
-            .filter_map(|name| {
+            .filter_map(|(name, id)| {
                 Some(Measurement::from((
                     name.clone(),
                     correct_rates.get(&name)?,
-                    time_stats.get(&name)?,
+                    relative_errors.get(&name)?,
+                    lp_gaps.get(&name)?,
+                    failures.get(&name)?,
+                    time_stats.get(&id)?,
                 )))
             })
             .collect()
@@ -188,6 +397,9 @@ impl Bencher {
                 vec![
                     m.get_solver_name(),
                     format!("{:3.2}%", m.get_correct_rate()),
+                    format!("{:3.2}%", m.get_relative_error()),
+                    format!("{:3.2}%", m.get_lp_gap()),
+                    Self::format_failures(&m.get_failures()),
                     format!(
                         "{:6.3}/{:6.3}/{:6.3}/{:6.3}",
                         time_stats.get_mean_time() / number_of_samples as f64,
@@ -207,6 +419,47 @@ impl Bencher {
         }
     }
 
+    /// Reports the solvers skipped because they can't handle the knapsacks, with the reasons.
+    ///
+    /// # Arguments
+    ///
+    /// * `skipped` - The names of the skipped solvers with the exceeded limits.
+    pub fn report_skipped(&self, skipped: &[(String, KnapsackError)]) {
+        if skipped.is_empty() {
+            return;
+        }
+
+        let list: String = skipped
+            .iter()
+            .map(|(name, error)| format!("- {}: {}\n", name, error))
+            .collect();
+        let output = format!("\nSkipped algorithms:\n\n{}", list);
+
+        if let Err(e) = self.reporter.report(&output) {
+            eprintln!("Failed to report metrics: {}", e);
+        }
+    }
+
+    /// Formats the failure counts of a solver for the report table.
+    ///
+    /// # Arguments
+    ///
+    /// * `failures` - A mapping of error kinds to failure counts.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The counts as "Kind: count" pairs, or "-" if the solver never failed.
+    fn format_failures(failures: &BTreeMap<String, usize>) -> String {
+        if failures.is_empty() {
+            return "-".to_string();
+        }
+        failures
+            .iter()
+            .map(|(kind, count)| format!("{}: {}", kind, count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     /// Formats data into a markdown table string.
     ///
     /// # Arguments
@@ -224,6 +477,9 @@ impl Bencher {
         let headers = vec![
             "Algorithm",
             "Success Rate",
+            "Relative Error",
+            "LP Gap",
+            "Failures",
             "Execution Time (ms) (mean/std_dev/median/median_abs_dev)",
         ];
         let mut widths = headers.iter().map(|h| h.len()).collect::<Vec<usize>>();
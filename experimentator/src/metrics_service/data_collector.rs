@@ -27,17 +27,10 @@ fn get_point_estimate(stat_name: &str, json: &Value) -> Result<f64, String> {
         .ok_or_else(|| format!("Failed to get {} estimate", stat_name))
 }
 
-fn capitalize_first(s: &str) -> String {
-    let mut chars = s.chars();
-    match chars.next() {
-        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-        None => String::new(),
-    }
-}
-
 /// Collects and processes benchmark statistics from criterion output
 ///
-/// Returns a HashMap mapping solver names to their performance statistics,
+/// Returns a HashMap mapping solver identifiers (see `KnapsackSolver::get_id`), which name
+/// the benchmark directories, to their performance statistics,
 /// converting nanoseconds to milliseconds in the process
 pub fn get_criterion_stats() -> Result<HashMap<String, TimeStats>, String> {
     let start_dir = get_criterion_start_dir();
@@ -76,7 +69,7 @@ pub fn get_criterion_stats() -> Result<HashMap<String, TimeStats>, String> {
             get_point_estimate("median_abs_dev", &json)?,
         ];
 
-        // Extract solver name from path components
+        // Extract solver identifier from path components
         let components: Vec<_> = entry
             .path()
             .components()
@@ -92,18 +85,14 @@ pub fn get_criterion_stats() -> Result<HashMap<String, TimeStats>, String> {
             .position(|&c| c == "criterion")
             .expect("'criterion' not found");
 
-        let solver_name = capitalize_first(
-            components[target_idx + criterion_idx + 2]
-                .to_string()
-                .as_ref(),
-        );
+        let solver_id = components[target_idx + criterion_idx + 2].to_string();
 
-        println!("{:?}", solver_name);
+        println!("{:?}", solver_id);
 
         // Store measurements if not already present
-        if !measurements.contains_key(&solver_name) {
+        if !measurements.contains_key(&solver_id) {
             measurements.insert(
-                solver_name,
+                solver_id,
                 TimeStats::from((
                     stats[0] / NS_TO_MS,
                     stats[1] / NS_TO_MS,
@@ -1,5 +1,6 @@
 use crate::metrics_service::models::time_stats::TimeStats;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Represents a measurement of a solver's performance metrics.
 ///
@@ -9,6 +10,12 @@ pub struct Measurement {
     solver_name: String,
     /// Percentage of correct solutions (0-100)
     correct_rate: f64,
+    /// Mean relative error compared to the best found solution in percent (0-100)
+    relative_error: f64,
+    /// Mean gap to the fractional knapsack upper bound in percent (0-100)
+    lp_gap: f64,
+    /// Number of failed runs for every kind of error
+    failures: BTreeMap<String, usize>,
     time_stats: TimeStats,
 }
 
@@ -31,6 +38,33 @@ impl Measurement {
         self.correct_rate
     }
 
+    /// Returns the mean relative error of the solver.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The mean relative error compared to the best found solution in percent (0-100)
+    pub fn get_relative_error(&self) -> f64 {
+        self.relative_error
+    }
+
+    /// Returns the mean gap of the solver to the fractional knapsack upper bound.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The mean gap to the value of the linear programming relaxation in percent (0-100)
+    pub fn get_lp_gap(&self) -> f64 {
+        self.lp_gap
+    }
+
+    /// Returns the number of failed runs of the solver for every kind of error.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeMap<String, usize>` - A mapping of error kinds (e.g. "CapacityTooLarge") to failure counts
+    pub fn get_failures(&self) -> BTreeMap<String, usize> {
+        self.failures.clone()
+    }
+
     /// Returns the timing statistics for the solver.
     ///
     /// # Returns
@@ -45,13 +79,16 @@ impl Measurement {
 ///
 /// # Arguments
 ///
-/// * `tuple` - A tuple containing (solver_name, correct_rate, time_stats)
-impl From<(String, &f64, &TimeStats)> for Measurement {
-    fn from(tuple: (String, &f64, &TimeStats)) -> Measurement {
+/// * `tuple` - A tuple containing (solver_name, correct_rate, relative_error, lp_gap, failures, time_stats)
+impl From<(String, &f64, &f64, &f64, &BTreeMap<String, usize>, &TimeStats)> for Measurement {
+    fn from(tuple: (String, &f64, &f64, &f64, &BTreeMap<String, usize>, &TimeStats)) -> Measurement {
         Measurement {
             solver_name: tuple.0,
             correct_rate: tuple.1.clone(),
-            time_stats: tuple.2.clone(),
+            relative_error: *tuple.2,
+            lp_gap: *tuple.3,
+            failures: tuple.4.clone(),
+            time_stats: tuple.5.clone(),
         }
     }
 }
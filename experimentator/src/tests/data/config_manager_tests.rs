@@ -78,3 +78,17 @@ fn test_read_rand_config_invalid() {
     let result = read_rand_config(&file_path);
     assert!(result.is_err());
 }
+
+#[test]
+/// Tests that the bundled configuration comparing the parallel dynamic programming reads and names known algorithms.
+///
+/// # Expected behavior
+///
+/// The configuration should be parsed and every algorithm name should be resolved by the service.
+fn test_read_parallel_config() {
+    let config = read_rand_config("experiment_parallel.json").unwrap();
+
+    assert!(config.algorithms.contains(&"dynamic".to_string()));
+    let algorithms = knapsack_library::algorithms_service::AlgorithmsService::get_algorithms_by_names(config.algorithms).unwrap();
+    assert_eq!(algorithms.len(), 4);
+}
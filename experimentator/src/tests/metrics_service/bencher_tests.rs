@@ -66,6 +66,8 @@ fn test_bench_group_empty_knapsacks() {
     let solvers = create_test_solvers();
     let empty_knapsacks: Vec<Knapsack> = vec![];
 
+    let solvers: Vec<&dyn KnapsackSolver> = solvers.iter().map(|solver| solver.as_ref()).collect();
+
     // Should not panic with empty knapsacks
     bencher.bench_group(&solvers, &empty_knapsacks, None, None, None, None);
 }
@@ -83,3 +85,17 @@ fn test_cleanup_after_experiment() {
     // Verify that criterion directory is cleaned up
     assert!(!Path::new("target/criterion").exists());
 }
+
+#[test]
+fn test_unsupported_solvers_skipped() {
+    let items: Vec<Item> = (1..=70).map(|i| Item::new(i, i)).collect();
+    let knapsacks = vec![Knapsack::new(100, items)];
+    let solvers = AlgorithmsService::get_algorithms_by_names(vec!["Bit mask".to_string(), "Dynamic".to_string()]).unwrap();
+
+    let (supported, skipped) = Bencher::split_supported(&solvers, &knapsacks);
+    let supported_names: Vec<String> = supported.iter().map(|solver| solver.get_name()).collect();
+    assert_eq!(supported_names, vec!["Dynamic".to_string()]);
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].0, "Bit mask");
+    assert_eq!(skipped[0].1.to_string(), "The number of items (70) exceeds the maximum allowed (63)");
+}
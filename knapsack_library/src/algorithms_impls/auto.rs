@@ -0,0 +1,339 @@
+use crate::algorithms_impls::bounded_dynamic::BoundedDynamicKnapsackSolver;
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
+use crate::algorithms_impls::greedy::GreedyKnapsackSolver;
+use crate::algorithms_impls::meet_in_the_middle::MeetInTheMiddleKnapsackSolver;
+use crate::algorithms_impls::profit_dynamic::ProfitDynamicKnapsackSolver;
+use crate::algorithms_impls::subset_sum::SubsetSumKnapsackSolver;
+use crate::models::auto_decision::{AutoChoice, AutoDecision};
+use crate::models::instance_features::InstanceFeatures;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::SolveContext;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities, MAX_TABLE_CELLS};
+use std::sync::OnceLock;
+
+/// A solver which inspects the knapsack and dispatches it to the most suitable exact algorithm.
+///
+/// The selection rules are implemented by `select`, which only depends on the `InstanceFeatures`
+/// of the knapsack and the memory budget, so they can be checked without solving anything.
+pub struct AutoKnapsackSolver {
+    memory_budget: u64, // The number of bytes the selected algorithm may allocate
+}
+
+impl AutoKnapsackSolver {
+    /// The largest number of items for which the full iteration over subsets is selected.
+    pub const BIT_MASK_MAX_ITEMS: usize = 16;
+    /// The largest number of items for which the meet in the middle is considered.
+    pub const MEET_IN_THE_MIDDLE_MAX_ITEMS: usize = MeetInTheMiddleKnapsackSolver::MAX_ITEMS;
+    /// The correlation from which the weights and the values are considered strongly correlated.
+    pub const STRONG_CORRELATION: f64 = 0.9;
+    /// The largest number of table cell updates the dynamic programming may perform.
+    pub const MAX_TABLE_UPDATES: u128 = 1 << 34;
+
+    /// Creates a solver with a memory budget of half of the available memory, but at most 8 GiB.
+    ///
+    /// The available memory is read only once per process, so creating solvers stays cheap.
+    ///
+    /// # Returns
+    ///
+    /// A new `AutoKnapsackSolver` instance.
+    pub fn new() -> Self {
+        Self { memory_budget: default_memory_budget() }
+    }
+
+    /// Creates a solver with the given memory budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory_budget` - The number of bytes the selected algorithm may allocate.
+    ///
+    /// # Returns
+    ///
+    /// A new `AutoKnapsackSolver` instance.
+    pub fn with_memory_budget(memory_budget: u64) -> Self {
+        Self { memory_budget }
+    }
+
+    /// Gets the number of bytes the selected algorithm may allocate.
+    pub fn get_memory_budget(&self) -> u64 {
+        self.memory_budget
+    }
+
+    /// Selects an algorithm for a knapsack with the given features.
+    ///
+    /// The rules are checked in order:
+    /// 1. Items with several copies are only supported by the bounded dynamic programming,
+    ///    so such knapsacks are unsupported if its table doesn't fit the memory budget.
+    /// 2. If all items fit, the greedy algorithm takes all of them.
+    /// 3. Subset-sum instances are solved by the bitset dynamic programming if the bitset fits the memory budget.
+    /// 4. Few items are solved by the full iteration over subsets.
+    /// 5. Weakly correlated weights and values give tight bounds for branch and bound.
+    /// 6. The dynamic programming over capacity or over values is used if its table fits the budgets.
+    /// 7. The meet in the middle is used if the halves fit the memory budget.
+    /// 8. Otherwise branch and bound is used, since it needs no extra memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `features` - The features of the knapsack.
+    /// * `memory_budget` - The number of bytes the selected algorithm may allocate.
+    ///
+    /// # Returns
+    ///
+    /// The `AutoDecision` with the selected algorithm and the explanation.
+    pub fn select(features: &InstanceFeatures, memory_budget: u64) -> AutoDecision {
+        let n = features.get_items_len();
+        let capacity = features.get_capacity() as u128;
+        let profit_sum = features.get_profit_sum();
+        let correlation = features.get_correlation();
+
+        if features.has_copies() {
+            let memory = bounded_table_memory(n, features.get_capacity());
+            if memory > memory_budget as u128 {
+                return AutoDecision::new(
+                    AutoChoice::Unsupported,
+                    format!(
+                        "some items have several copies, but the bounded dynamic programming over capacity {} needs up to {} bytes (budget {})",
+                        capacity, memory, memory_budget
+                    ),
+                );
+            }
+            return AutoDecision::new(
+                AutoChoice::BoundedDynamic,
+                format!(
+                    "some items have several copies, only the bounded dynamic programming takes them into account, its table needs up to {} bytes (budget {})",
+                    memory, memory_budget
+                ),
+            );
+        }
+        if features.get_weight_sum() <= capacity {
+            return AutoDecision::new(
+                AutoChoice::Greedy,
+                format!("all items fit (total weight {} <= capacity {}), so all of them are taken", features.get_weight_sum(), capacity),
+            );
+        }
+        // The bitset solver rejects capacities beyond the table limit, whatever the budget
+        if features.is_subset_sum() && capacity < MAX_TABLE_CELLS as u128 {
+            let memory = subset_sum_memory(capacity);
+            if memory <= memory_budget as u128 {
+                return AutoDecision::new(
+                    AutoChoice::SubsetSum,
+                    format!("values are proportional to weights, the bitset over capacity {} needs {} bytes (budget {})", capacity, memory, memory_budget),
+                );
+            }
+        }
+        if n <= Self::BIT_MASK_MAX_ITEMS {
+            return AutoDecision::new(
+                AutoChoice::BitMask,
+                format!("only {} items (<= {}), iterating over all subsets is the cheapest", n, Self::BIT_MASK_MAX_ITEMS),
+            );
+        }
+        if correlation < Self::STRONG_CORRELATION {
+            return AutoDecision::new(
+                AutoChoice::BranchAndBound,
+                format!("weights and values are weakly correlated (r = {:.2}), so the bounds prune the search well", correlation),
+            );
+        }
+
+        // The table over the smaller range is preferred, if any of them fits
+        let mut tables = [(AutoChoice::Dynamic, capacity, "capacity"), (AutoChoice::ProfitDynamic, profit_sum, "value")];
+        tables.sort_by_key(|&(_, range, _)| range);
+        for (choice, range, label) in tables {
+            let updates = n as u128 * (range + 1);
+            let memory = table_memory(n, range);
+            if updates <= Self::MAX_TABLE_UPDATES && memory <= memory_budget as u128 {
+                return AutoDecision::new(
+                    choice,
+                    format!(
+                        "weights and values are strongly correlated (r = {:.2}) and the table over {} {} needs {} bytes (budget {})",
+                        correlation, label, range, memory, memory_budget
+                    ),
+                );
+            }
+        }
+
+        if n <= Self::MEET_IN_THE_MIDDLE_MAX_ITEMS {
+            let memory = meet_in_the_middle_memory(n);
+            if memory <= memory_budget as u128 {
+                return AutoDecision::new(
+                    AutoChoice::MeetInTheMiddle,
+                    format!(
+                        "no dynamic programming table fits, but the halves of {} items need {} bytes (budget {})",
+                        n, memory, memory_budget
+                    ),
+                );
+            }
+        }
+
+        AutoDecision::new(
+            AutoChoice::BranchAndBound,
+            format!(
+                "no table fits the budget of {} bytes for {} items, branch and bound needs no extra memory",
+                memory_budget, n
+            ),
+        )
+    }
+
+    /// Selects an algorithm for the knapsack with the memory budget of this solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` to inspect.
+    ///
+    /// # Returns
+    ///
+    /// The `AutoDecision` with the selected algorithm and the explanation.
+    pub fn decide(&self, knapsack: &Knapsack) -> AutoDecision {
+        Self::select(&InstanceFeatures::from_knapsack(knapsack), self.memory_budget)
+    }
+
+    /// Solves the knapsack problem with the selected algorithm and returns the decision as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// The `KnapsackSolution` of the selected algorithm with the `AutoDecision`.
+    pub fn solve_with_decision(&self, knapsack: &Knapsack) -> Result<(KnapsackSolution, AutoDecision), KnapsackError> {
+        let decision = self.decide(knapsack);
+        let solution = create_solver(decision.get_choice())?.solve_with_items(knapsack)?;
+        Ok((solution, decision))
+    }
+}
+
+impl Default for AutoKnapsackSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KnapsackSolver for AutoKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Auto".
+    fn get_name(&self) -> String {
+        "Auto".to_string()
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "auto".
+    fn get_id(&self) -> String {
+        "auto".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "depends on the selected algorithm", "at most the memory budget")
+    }
+
+    /// Solves the knapsack problem with the algorithm selected for the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value that can be achieved.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        create_solver(self.decide(knapsack).get_choice())?.solve(knapsack)
+    }
+
+    /// Solves the knapsack problem with the algorithm selected for the knapsack and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items selected by the chosen algorithm.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.solve_with_decision(knapsack).map(|(solution, _)| solution)
+    }
+
+    /// Solves the knapsack problem with the algorithm selected for the knapsack until the context stops it.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    /// * `context` - The deadline and the cancellation token of the solver.
+    ///
+    /// # Returns
+    ///
+    /// The `SolveOutcome` of the chosen algorithm.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        create_solver(self.decide(knapsack).get_choice())?.solve_with_context(knapsack, context)
+    }
+}
+
+/// Creates the solver for the selected algorithm, or returns `CapacityTooLarge` if none was selected.
+fn create_solver(choice: AutoChoice) -> Result<Box<dyn KnapsackSolver>, KnapsackError> {
+    Ok(match choice {
+        AutoChoice::Greedy => Box::new(GreedyKnapsackSolver),
+        AutoChoice::BitMask => Box::new(BitMaskKnapsackSolver),
+        AutoChoice::Dynamic => Box::new(DynamicKnapsackSolver),
+        AutoChoice::ProfitDynamic => Box::new(ProfitDynamicKnapsackSolver),
+        AutoChoice::MeetInTheMiddle => Box::new(MeetInTheMiddleKnapsackSolver),
+        AutoChoice::BranchAndBound => Box::new(BranchAndBoundKnapsackSolver),
+        AutoChoice::BoundedDynamic => Box::new(BoundedDynamicKnapsackSolver),
+        AutoChoice::SubsetSum => Box::new(SubsetSumKnapsackSolver),
+        AutoChoice::Unsupported => return Err(KnapsackError::CapacityTooLarge),
+    })
+}
+
+/// Estimates the bytes of a dynamic programming table over `range + 1` cells with decisions of `n` items.
+fn table_memory(n: usize, range: u128) -> u128 {
+    // Two rows of values and a bitset of decisions per item
+    2 * (range + 1) * 8 + n as u128 * (range / 64 + 1) * 8
+}
+
+/// Estimates the bytes of the bounded dynamic programming table over `capacity + 1` cells for `n` items.
+fn bounded_table_memory(n: usize, capacity: u64) -> u128 {
+    // Every item is split into at most one bundle per bit of the capacity and a remainder
+    let bundles = n * (u64::BITS - capacity.leading_zeros() + 1) as usize;
+    table_memory(bundles, capacity as u128)
+}
+
+/// Estimates the bytes of the reachability bitset over `capacity + 1` weights with the items which reached them.
+fn subset_sum_memory(capacity: u128) -> u128 {
+    (capacity / 64 + 1) * 8 + (capacity + 1) * 4
+}
+
+/// Estimates the bytes of the subset sums of both halves of `n` items.
+fn meet_in_the_middle_memory(n: usize) -> u128 {
+    // Every subset sum holds the weight, the value and the mask
+    2 * (1u128 << n.div_ceil(2)) * 24
+}
+
+/// Computes the default memory budget on the first call and returns the same one afterwards.
+fn default_memory_budget() -> u64 {
+    static MEMORY_BUDGET: OnceLock<u64> = OnceLock::new();
+    *MEMORY_BUDGET.get_or_init(|| {
+        let max_budget = MAX_TABLE_CELLS * 8;
+        available_memory().map_or(max_budget, |memory| (memory / 2).min(max_budget))
+    })
+}
+
+/// Reads the amount of available memory in bytes, if the system reports it.
+fn available_memory() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|line| line.starts_with("MemAvailable:"))?;
+    let kibibytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    kibibytes.checked_mul(1024)
+}
@@ -0,0 +1,124 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solver_capabilities::{check_decisions_size, Exactness, SolverCapabilities, MAX_TABLE_CELLS};
+
+/// Dynamic Programming implementation of the bounded Knapsack solver.
+///
+/// Every item may be taken up to its count times. Instead of expanding the copies, the count of
+/// every item is split into bundles of 1, 2, 4, ... copies and a remainder, so that any number of
+/// copies up to the count is a sum of distinct bundles. The bundles are solved as a 0/1 knapsack.
+/// Items with a count of 1 behave exactly as in `DynamicKnapsackSolver`.
+/// Time complexity: O(W * Σ log c_i) where c_i are the counts of the items
+/// Space complexity: O(W), or O(W * Σ log c_i) bits when the selected items are reconstructed
+pub struct BoundedDynamicKnapsackSolver;
+
+impl KnapsackSolver for BoundedDynamicKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Bounded dynamic".to_string()
+    }
+
+    fn get_id(&self) -> String {
+        "bounded_dynamic".to_string()
+    }
+
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(W * Σ log c_i)", "O(W + W * Σ log c_i / 64)").with_max_capacity(MAX_TABLE_CELLS - 1)
+    }
+
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.binary_split_dynamic(knapsack, false).map(|(value, _)| value)
+    }
+
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.binary_split_dynamic(knapsack, true)
+            .map(|(_, items)| KnapsackSolution::new(knapsack, items))
+    }
+}
+
+impl BoundedDynamicKnapsackSolver {
+    /// Splits the counts of the items into bundles of powers of two and a remainder.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    ///
+    /// # Returns
+    /// * Vector of `(item index, copies)` bundles. Bundles heavier than the capacity are skipped.
+    fn split(knapsack: &Knapsack) -> Vec<(usize, u64)> {
+        let capacity = knapsack.get_capacity();
+        let mut bundles = Vec::new();
+
+        for i in 0..knapsack.get_items_len() {
+            let item = knapsack.get_item(i);
+            // No more copies than fit in the knapsack can be taken anyway
+            let fitting = capacity.checked_div(item.get_weight()).unwrap_or(u64::MAX);
+            let mut left = item.get_count().min(fitting);
+            let mut size = 1;
+
+            while left > 0 {
+                let copies = size.min(left);
+                bundles.push((i, copies));
+                left -= copies;
+                size *= 2;
+            }
+        }
+
+        bundles
+    }
+
+    /// Runs the 0/1 dynamic programming over the bundles.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    /// * `track_items` - Whether to keep the decisions needed to restore the selected items.
+    ///
+    /// # Returns
+    /// * The maximum value and the selected items, one index per copy (empty if they are not tracked).
+    fn binary_split_dynamic(&self, knapsack: &Knapsack, track_items: bool) -> Result<(u64, Vec<usize>), KnapsackError> {
+        knapsack.check_overflow()?;
+        // The table must not exceed the declared capacity limit
+        self.get_capabilities().check(knapsack)?;
+        let capacity = knapsack.get_capacity() as usize;
+        let bundles = Self::split(knapsack);
+
+        let words = if track_items { capacity / 64 + 1 } else { 0 };
+        check_decisions_size(bundles.len(), words)?;
+
+        // One row is enough when iterating the capacity backwards
+        let mut best = vec![0; capacity + 1];
+        // taken[b] is a bitset over capacities: whether bundle b is taken in the best solution for w
+        let mut taken = vec![vec![0u64; words]; bundles.len()];
+
+        for (row, &(i, copies)) in taken.iter_mut().zip(bundles.iter()) {
+            let item = knapsack.get_item(i);
+            let weight = (item.get_weight() * copies) as usize;
+            let value = item.get_value() * copies;
+
+            for w in (weight..=capacity).rev() {
+                if best[w - weight] + value > best[w] {
+                    best[w] = best[w - weight] + value;
+                    if track_items {
+                        row[w / 64] |= 1 << (w % 64);
+                    }
+                }
+            }
+        }
+
+        if !track_items {
+            return Ok((best[capacity], Vec::new()));
+        }
+
+        // Walk the decisions back from the last bundle
+        let mut items = Vec::new();
+        let mut w = capacity;
+        for (b, &(i, copies)) in bundles.iter().enumerate().rev() {
+            if taken[b][w / 64] & (1 << (w % 64)) != 0 {
+                items.extend(std::iter::repeat_n(i, copies as usize));
+                w -= (knapsack.get_item(i).get_weight() * copies) as usize;
+            }
+        }
+
+        Ok((best[capacity], items))
+    }
+}
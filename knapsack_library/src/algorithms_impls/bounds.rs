@@ -0,0 +1,263 @@
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+
+/// Computes the Dantzig upper bound, the value of the linear programming relaxation rounded down.
+///
+/// Copies of bounded items are taken into account.
+///
+/// # Arguments
+///
+/// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+///
+/// # Returns
+///
+/// An upper bound on the optimal value.
+pub fn dantzig_bound(knapsack: &Knapsack) -> u64 {
+    let (base_value, items) = sorted_items(knapsack);
+    base_value.saturating_add(dantzig_bound_sorted(&items, knapsack.get_capacity()))
+}
+
+/// Computes the Martello–Toth (U2) upper bound, which is never weaker than the Dantzig bound.
+///
+/// The bound branches on the critical item, so it assumes every item is taken at most once.
+/// For bounded knapsacks (items with several copies) the Dantzig bound is returned.
+///
+/// # Arguments
+///
+/// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+///
+/// # Returns
+///
+/// An upper bound on the optimal value.
+pub fn martello_toth_bound(knapsack: &Knapsack) -> u64 {
+    if (0..knapsack.get_items_len()).any(|i| knapsack.get_item(i).get_count() > 1) {
+        return dantzig_bound(knapsack);
+    }
+    let (base_value, items) = sorted_items(knapsack);
+    base_value.saturating_add(martello_toth_bound_sorted(&items, knapsack.get_capacity()))
+}
+
+/// Computes the Lagrangian upper bound for the given multiplier of the capacity constraint.
+///
+/// For a multiplier `λ` the bound is `λC + Σ max(0, v_i - λw_i)`, where every copy of an item counts.
+/// It is valid for any `λ ≥ 0`; the smallest bound is reached at the ratio of the critical item,
+/// where it equals the Dantzig bound.
+///
+/// # Arguments
+///
+/// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+/// * `multiplier` - The multiplier `λ` as a fraction `(numerator, denominator)`.
+///
+/// # Returns
+///
+/// An upper bound on the optimal value, or `KnapsackError::InvalidParameter` if the denominator is zero.
+pub fn lagrangian_bound(knapsack: &Knapsack, multiplier: (u64, u64)) -> Result<u64, KnapsackError> {
+    let (numerator, denominator) = (multiplier.0 as u128, multiplier.1 as u128);
+    if denominator == 0 {
+        return Err(KnapsackError::InvalidParameter("the denominator of the multiplier must be positive".to_string()));
+    }
+
+    let capacity = knapsack.get_capacity();
+    let mut bound = numerator * capacity as u128;
+    for i in 0..knapsack.get_items_len() {
+        let item = knapsack.get_item(i);
+        // Items that don't fit can't be taken, so they don't need to be relaxed
+        if item.get_weight() > capacity {
+            continue;
+        }
+        let gain = (denominator * item.get_value() as u128).saturating_sub(numerator * item.get_weight() as u128);
+        bound = bound.saturating_add(gain.saturating_mul(item.get_count() as u128));
+    }
+    Ok(u64::try_from(bound / denominator).unwrap_or(u64::MAX))
+}
+
+/// Computes the surrogate upper bound of a multi-dimensional knapsack for the given multipliers.
+///
+/// The constraints are combined into one `Σ_k μ_k w_k ≤ Σ_k μ_k c_k`, which every solution satisfies,
+/// and the Dantzig bound of the resulting knapsack is returned. Items that exceed any capacity are dropped.
+///
+/// # Arguments
+///
+/// * `knapsack` - A reference to the `MultiDimensionalKnapsack`.
+/// * `multipliers` - The multiplier `μ_k` of every constraint.
+///
+/// # Returns
+///
+/// An upper bound on the optimal value, or `KnapsackError::InvalidParameter` if the number of multipliers
+/// differs from the number of dimensions, or `KnapsackError::ArithmeticOverflow` if the combined weights don't fit in `u64`.
+pub fn surrogate_bound(knapsack: &MultiDimensionalKnapsack, multipliers: &[u64]) -> Result<u64, KnapsackError> {
+    let capacities = knapsack.get_capacities();
+    if multipliers.len() != capacities.len() {
+        return Err(KnapsackError::InvalidParameter(format!(
+            "expected {} multipliers, but got {}",
+            capacities.len(),
+            multipliers.len()
+        )));
+    }
+
+    let combine = |weights: &[u64]| -> Result<u64, KnapsackError> {
+        let combined: u128 = weights.iter().zip(multipliers).map(|(&w, &m)| w as u128 * m as u128).sum();
+        u64::try_from(combined).map_err(|_| KnapsackError::ArithmeticOverflow)
+    };
+
+    let mut items = Vec::new();
+    for i in 0..knapsack.get_items_len() {
+        let item = knapsack.get_item(i);
+        if item.get_weights().iter().zip(capacities).all(|(w, c)| w <= c) {
+            items.push(Item::new(combine(item.get_weights())?, item.get_value()));
+        }
+    }
+    Ok(dantzig_bound(&Knapsack::new(combine(capacities)?, items)))
+}
+
+/// Computes the surrogate upper bound of a multiple knapsack problem.
+///
+/// The knapsacks are merged into one with the total capacity, and the items that fit in none
+/// of the knapsacks are dropped. The Martello–Toth bound of the merged knapsack is returned.
+///
+/// # Arguments
+///
+/// * `knapsack` - A reference to the `MultipleKnapsack`.
+///
+/// # Returns
+///
+/// An upper bound on the optimal value.
+pub fn multiple_surrogate_bound(knapsack: &MultipleKnapsack) -> u64 {
+    let capacities = knapsack.get_capacities();
+    let max_capacity = capacities.iter().copied().max().unwrap_or(0);
+    let total_capacity = capacities.iter().fold(0u64, |total, &c| total.saturating_add(c));
+    let items = (0..knapsack.get_items_len())
+        .map(|i| knapsack.get_item(i))
+        .filter(|item| item.get_weight() <= max_capacity)
+        .map(|item| Item::new(item.get_weight(), item.get_value()))
+        .collect();
+    martello_toth_bound(&Knapsack::new(total_capacity, items))
+}
+
+/// Computes the relative gap between a value and the Martello–Toth bound of the knapsack.
+///
+/// The optimal value lies between the value and the bound, so the gap is an upper estimate
+/// of how far a heuristic result is from the optimum.
+///
+/// # Arguments
+///
+/// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+/// * `value` - The value of a solution of the knapsack.
+///
+/// # Returns
+///
+/// The gap `(bound - value) / bound` from 0 to 1, or 0 if the bound is zero.
+pub fn optimality_gap(knapsack: &Knapsack, value: u64) -> f64 {
+    let bound = martello_toth_bound(knapsack);
+    if bound == 0 {
+        return 0.0;
+    }
+    bound.saturating_sub(value) as f64 / bound as f64
+}
+
+/// Computes the Dantzig upper bound for the given items and capacity.
+///
+/// Branch and bound solvers can compute the bounds of their subproblems with it without sorting the items again.
+///
+/// # Arguments
+/// * `items` - Items with non-zero weights sorted by decreasing value-to-weight ratio.
+/// * `capacity` - The available capacity.
+pub fn dantzig_bound_sorted(items: &[(u64, u64)], capacity: u64) -> u64 {
+    let mut remaining = capacity;
+    let mut value: u64 = 0;
+
+    for &(w, v) in items {
+        if w > remaining {
+            return value.saturating_add((remaining as u128 * v as u128 / w as u128) as u64);
+        }
+        remaining -= w;
+        value = value.saturating_add(v);
+    }
+    value
+}
+
+/// Computes the Martello–Toth upper bound for the given items and capacity.
+///
+/// The bound takes whole items until the critical item `c` (the first one that doesn't fit)
+/// and then considers both branches for it: without it, the remaining capacity is filled
+/// with the ratio of item `c + 1`; with it, the overweight is removed with the ratio of item `c - 1`.
+/// Branch and bound solvers can compute the bounds of their subproblems with it without sorting the items again.
+/// Like the Dantzig bound, it saturates at `u64::MAX` instead of overflowing.
+///
+/// # Arguments
+/// * `items` - Items with non-zero weights sorted by decreasing value-to-weight ratio.
+/// * `capacity` - The available capacity.
+pub fn martello_toth_bound_sorted(items: &[(u64, u64)], capacity: u64) -> u64 {
+    let mut remaining = capacity;
+    let mut value: u64 = 0;
+    let mut critical = items.len();
+
+    for (i, &(w, v)) in items.iter().enumerate() {
+        if w > remaining {
+            critical = i;
+            break;
+        }
+        remaining -= w;
+        value = value.saturating_add(v);
+    }
+
+    // All items fit
+    if critical == items.len() {
+        return value;
+    }
+
+    let (critical_weight, critical_value) = items[critical];
+
+    // Upper bound without the critical item
+    let without_critical = match items.get(critical + 1) {
+        Some(&(w, v)) => value.saturating_add((remaining as u128 * v as u128 / w as u128) as u64),
+        None => value,
+    };
+
+    // Upper bound with the critical item, only defined when there is an item to remove
+    let with_critical = match critical.checked_sub(1).map(|i| items[i]) {
+        Some((w, v)) => {
+            let overweight = (critical_weight - remaining) as u128;
+            let removed = (overweight * v as u128).div_ceil(w as u128);
+            let bound = (value as u128 + critical_value as u128).saturating_sub(removed);
+            u64::try_from(bound).unwrap_or(u64::MAX)
+        }
+        None => 0,
+    };
+
+    without_critical.max(with_critical)
+}
+
+/// Prepares the items of the knapsack for the bounds on sorted items.
+///
+/// Items without weight are always taken, items without value or heavier than the capacity are dropped.
+/// The copies of an item that fit in the knapsack are merged into one item with the same ratio.
+///
+/// # Returns
+/// * The total value of the items without weight and the other items sorted by decreasing value-to-weight ratio.
+fn sorted_items(knapsack: &Knapsack) -> (u64, Vec<(u64, u64)>) {
+    let capacity = knapsack.get_capacity();
+    let mut base_value: u64 = 0;
+    let mut items = Vec::new();
+
+    for i in 0..knapsack.get_items_len() {
+        let item = knapsack.get_item(i);
+        let (weight, value, count) = (item.get_weight(), item.get_value(), item.get_count());
+        if value == 0 || weight > capacity || count == 0 {
+            continue;
+        }
+        if weight == 0 {
+            base_value = base_value.saturating_add(value.saturating_mul(count));
+            continue;
+        }
+        let copies = count.min(capacity / weight);
+        items.push((weight * copies, value.saturating_mul(copies)));
+    }
+
+    items.sort_by(|&a, &b| compare_ratios(b, a));
+    (base_value, items)
+}
@@ -0,0 +1,256 @@
+use crate::algorithms_impls::bounds::martello_toth_bound_sorted;
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::{SolveContext, StopCheck};
+use crate::models::solve_observer::SolveEvent;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
+
+/// Branch and bound implementation of the Knapsack solver.
+///
+/// This solver sorts the items by their value-to-weight ratio and explores the decision tree
+/// depth-first, taking items before skipping them. Subtrees are pruned with the Martello–Toth
+/// upper bound, which is never weaker than the Dantzig fractional bound, and the incumbent
+/// is seeded with the greedy solution.
+/// Time complexity: O(2^n * n) in the worst case, but much faster on most instances.
+/// Space complexity: O(n)
+pub struct BranchAndBoundKnapsackSolver;
+
+/// Statistics of a branch and bound run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BranchAndBoundStats {
+    solution: KnapsackSolution, // The best solution found
+    nodes_explored: u64,        // The number of visited nodes of the decision tree
+}
+
+impl BranchAndBoundStats {
+    /// Gets the best solution found by the solver.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `KnapsackSolution` with the maximum value.
+    pub fn get_solution(&self) -> &KnapsackSolution {
+        &self.solution
+    }
+
+    /// Gets the number of nodes of the decision tree visited by the solver.
+    ///
+    /// # Returns
+    ///
+    /// The number of explored nodes as a `u64`.
+    pub fn get_nodes_explored(&self) -> u64 {
+        self.nodes_explored
+    }
+}
+
+impl KnapsackSolver for BranchAndBoundKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Branch and bound".
+    fn get_name(&self) -> String {
+        "Branch and bound".to_string()
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "branch_and_bound".
+    fn get_id(&self) -> String {
+        "branch_and_bound".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(2^n * n)", "O(n)")
+    }
+
+    /// Solves the knapsack problem using branch and bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value representing the maximum value that can be achieved by fitting items
+    /// in the knapsack without exceeding the capacity.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    /// Solves the knapsack problem using branch and bound and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.solve_with_stats(knapsack).map(|stats| stats.solution)
+    }
+
+    /// Solves the knapsack problem using branch and bound until the context stops the solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    /// * `context` - The deadline and the cancellation token of the solver.
+    ///
+    /// # Returns
+    ///
+    /// A `SolveOutcome` with the best combination found, which isn't proven if the solver was stopped.
+    /// It is never worse than the greedy solution.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        let (stats, proven) = Self::search(knapsack, context)?;
+        Ok(SolveOutcome::new(stats.solution, proven))
+    }
+}
+
+impl BranchAndBoundKnapsackSolver {
+    /// Solves the knapsack problem and reports how much of the decision tree was explored.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `BranchAndBoundStats` with the best solution and the number of explored nodes.
+    pub fn solve_with_stats(&self, knapsack: &Knapsack) -> Result<BranchAndBoundStats, KnapsackError> {
+        Ok(Self::search(knapsack, &SolveContext::new())?.0)
+    }
+
+    /// Explores the decision tree until it is finished or the context stops the search.
+    ///
+    /// # Returns
+    ///
+    /// The `BranchAndBoundStats` of the search and whether it was finished.
+    fn search(knapsack: &Knapsack, context: &SolveContext) -> Result<(BranchAndBoundStats, bool), KnapsackError> {
+        knapsack.check_overflow()?;
+        let capacity = knapsack.get_capacity();
+        let mut base_items = Vec::new();
+        let mut indices: Vec<usize> = Vec::with_capacity(knapsack.get_items_len());
+
+        for i in 0..knapsack.get_items_len() {
+            let item = knapsack.get_item(i);
+            if item.get_value() == 0 || item.get_weight() > capacity {
+                // Such items never improve a solution
+                continue;
+            }
+            if item.get_weight() == 0 {
+                // Such items are always taken
+                base_items.push(i);
+                continue;
+            }
+            indices.push(i);
+        }
+
+        // Sort by value-to-weight ratio (descending order), compared exactly by cross-multiplication
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+        indices.sort_by(|&a, &b| compare_ratios(pair(b), pair(a)));
+        let items: Vec<(u64, u64)> = indices.iter().map(|&i| pair(i)).collect();
+
+        let greedy_path = Self::greedy_path(&items, capacity);
+        let mut search = Search {
+            items: &items,
+            path: Vec::new(),
+            best_value: greedy_path.iter().map(|&k| items[k].1).sum(),
+            best_path: greedy_path,
+            nodes_explored: 0,
+            stop: StopCheck::new(context),
+        };
+        search.stop.notify(SolveEvent::IncumbentFound { value: search.best_value });
+        search.explore(0, capacity, 0);
+
+        base_items.extend(search.best_path.iter().map(|&k| indices[k]));
+        let stats = BranchAndBoundStats {
+            solution: KnapsackSolution::new(knapsack, base_items),
+            nodes_explored: search.nodes_explored,
+        };
+        Ok((stats, !search.stop.is_stopped()))
+    }
+
+    /// Computes the greedy solution, which is used as the initial incumbent.
+    ///
+    /// # Arguments
+    /// * `items` - Items sorted by decreasing value-to-weight ratio.
+    /// * `capacity` - The capacity of the knapsack.
+    ///
+    /// # Returns
+    /// * Positions of the greedily taken items.
+    fn greedy_path(items: &[(u64, u64)], capacity: u64) -> Vec<usize> {
+        let mut remaining = capacity;
+        let mut path = Vec::new();
+        for (k, &(w, _)) in items.iter().enumerate() {
+            if w <= remaining {
+                remaining -= w;
+                path.push(k);
+            }
+        }
+        path
+    }
+}
+
+/// State of the depth-first search over items sorted by value-to-weight ratio.
+struct Search<'a> {
+    items: &'a [(u64, u64)], // Items sorted by decreasing value-to-weight ratio
+    path: Vec<usize>,        // Positions of the items taken on the current branch
+    best_path: Vec<usize>,   // Positions of the items of the best solution found
+    best_value: u64,         // The value of the best solution found
+    nodes_explored: u64,     // The number of visited nodes of the decision tree
+    stop: StopCheck<'a>,     // Checks whether the search must stop
+}
+
+impl Search<'_> {
+    /// Depth-first exploration of the decision tree.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the current item being considered.
+    /// * `remaining` - The remaining capacity of the knapsack.
+    /// * `current_value` - The total value of the selected items so far.
+    fn explore(&mut self, index: usize, remaining: u64, current_value: u64) {
+        if self.stop.should_stop() {
+            return;
+        }
+        self.nodes_explored += 1;
+
+        if current_value > self.best_value {
+            self.best_value = current_value;
+            self.best_path.clone_from(&self.path);
+            self.stop.notify(SolveEvent::IncumbentFound { value: current_value });
+        }
+
+        if index == self.items.len() {
+            return;
+        }
+
+        // Prune the subtree if it can't contain a better solution
+        let bound = martello_toth_bound_sorted(&self.items[index..], remaining);
+        if current_value + bound <= self.best_value {
+            return;
+        }
+
+        // Case 1: Take the item at the current index (if it fits in the knapsack)
+        let (weight, value) = self.items[index];
+        if weight <= remaining {
+            self.path.push(index);
+            self.explore(index + 1, remaining - weight, current_value + value);
+            self.path.pop();
+        }
+
+        // Case 2: Don't take the item at the current index
+        self.explore(index + 1, remaining, current_value);
+    }
+}
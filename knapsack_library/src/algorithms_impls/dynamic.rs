@@ -1,11 +1,17 @@
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
 use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::SolveContext;
+use crate::models::solve_observer::SolveEvent;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::{check_decisions_size, Exactness, SolverCapabilities, MAX_TABLE_CELLS};
 
 /// Dynamic Programming implementation of the Knapsack solver
 ///
 /// This solver uses a bottom-up dynamic programming approach to solve the 0/1 knapsack problem.
 /// Time complexity: O(nW) where n is the number of items and W is the capacity
-/// Space complexity: O(W)
+/// Space complexity: O(W), or O(nW) bits when the selected items are reconstructed
 pub struct DynamicKnapsackSolver;
 
 /// Solves the knapsack problem using dynamic programming
@@ -23,12 +29,21 @@ impl KnapsackSolver for DynamicKnapsackSolver {
         "Dynamic".to_string()
     }
 
-    fn solve(&self, knapsack: &Knapsack) -> Result<u64, String> {
+    fn get_id(&self) -> String {
+        "dynamic".to_string()
+    }
+
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(nW)", "O(W + nW / 64)").with_max_capacity(MAX_TABLE_CELLS - 1)
+    }
+
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        knapsack.check_overflow()?;
         let n = knapsack.get_items_len();
         let capacity = knapsack.get_capacity();
 
         if capacity >= usize::MAX as u64 {
-            return Err("Capacity too large to process".to_string());
+            return Err(KnapsackError::CapacityTooLarge);
         }
 
         let capacity = capacity as usize;
@@ -55,4 +70,64 @@ impl KnapsackSolver for DynamicKnapsackSolver {
 
         Ok(prev[capacity])
     }
+
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        Ok(self.solve_with_context(knapsack, &SolveContext::new())?.into_solution())
+    }
+
+    // The context is checked after every row, and the best value of the filled rows is reported as the incumbent.
+    // The items of the incumbent are unknown until the table is filled, so a stopped solver returns `Cancelled`.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        knapsack.check_overflow()?;
+        let n = knapsack.get_items_len();
+        let capacity = knapsack.get_capacity();
+
+        if capacity >= usize::MAX as u64 {
+            return Err(KnapsackError::CapacityTooLarge);
+        }
+
+        let capacity = capacity as usize;
+        let words = capacity / 64 + 1;
+        check_decisions_size(n, words)?;
+
+        let mut prev = vec![0; capacity + 1];
+        let mut curr = vec![0; capacity + 1];
+        // taken[i] is a bitset over capacities: whether item i is taken in the best solution for w
+        let mut taken = vec![vec![0u64; words]; n];
+
+        for (i, row) in taken.iter_mut().enumerate() {
+            let item = knapsack.get_item(i);
+            let weight = item.get_weight() as usize;
+            let value = item.get_value();
+
+            for w in 0..=capacity {
+                curr[w] = if weight <= w && prev[w - weight] + value > prev[w] {
+                    row[w / 64] |= 1 << (w % 64);
+                    prev[w - weight] + value
+                } else {
+                    prev[w]
+                };
+            }
+            if curr[capacity] > prev[capacity] {
+                context.notify(SolveEvent::IncumbentFound { value: curr[capacity] });
+            }
+            std::mem::swap(&mut prev, &mut curr);
+            context.notify(SolveEvent::RowCompleted { row: i + 1, rows: n });
+            if context.is_stopped() {
+                return Err(KnapsackError::Cancelled);
+            }
+        }
+
+        // Walk the decisions back from the last item
+        let mut items = Vec::new();
+        let mut w = capacity;
+        for i in (0..n).rev() {
+            if taken[i][w / 64] & (1 << (w % 64)) != 0 {
+                items.push(i);
+                w -= knapsack.get_item(i).get_weight() as usize;
+            }
+        }
+
+        Ok(SolveOutcome::new(KnapsackSolution::new(knapsack, items), true))
+    }
 }
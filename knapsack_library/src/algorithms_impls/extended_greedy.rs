@@ -0,0 +1,81 @@
+use crate::algorithms_impls::greedy::greedy_items;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
+
+/// Extended greedy implementation of the Knapsack solver.
+///
+/// This solver returns the better of the greedy solution and the most valuable single item
+/// fitting in the knapsack. The greedy items together with the first item it skips are worth more than
+/// the optimum, so one of the two candidates is worth at least half of it.
+/// Time complexity: O(n log n)
+/// Space complexity: O(n)
+pub struct ExtendedGreedyKnapsackSolver;
+
+impl KnapsackSolver for ExtendedGreedyKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Extended greedy".
+    fn get_name(&self) -> String {
+        "Extended greedy".to_string()
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "extended_greedy".
+    fn get_id(&self) -> String {
+        "extended_greedy".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Approximate(0.5), "O(n log n)", "O(n)")
+    }
+
+    /// Solves the knapsack problem using the extended greedy heuristic.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value representing the total value of the selected items, at least half of the optimum.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    /// Solves the knapsack problem using the extended greedy heuristic and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the greedy items or the single best item, whichever is worth more.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        let greedy = KnapsackSolution::new(knapsack, greedy_items(knapsack));
+
+        let best_item = (0..knapsack.get_items_len())
+            .filter(|&i| knapsack.get_item(i).get_weight() <= knapsack.get_capacity())
+            .max_by_key(|&i| knapsack.get_item(i).get_value());
+        match best_item {
+            Some(i) if knapsack.get_item(i).get_value() > greedy.get_total_value() => {
+                Ok(KnapsackSolution::new(knapsack, vec![i]))
+            }
+            _ => Ok(greedy),
+        }
+    }
+}
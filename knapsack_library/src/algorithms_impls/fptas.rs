@@ -0,0 +1,198 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::{name_to_id, KnapsackSolver};
+use crate::models::solver_capabilities::{check_decisions_size, Exactness, SolverCapabilities};
+
+/// FPTAS (Fully Polynomial-Time Approximation Scheme) implementation of the Knapsack solver.
+///
+/// This solver scales the values of the items down by `K = ε * v_max / n` and runs a dynamic
+/// programming indexed by the scaled value, which stores the minimum weight needed for it.
+/// The returned value is guaranteed to be at least `(1 - ε) * OPT`.
+/// Time complexity: O(n^2 / ε)
+/// Space complexity: O(n^2 / ε), or O(n^3 / ε) bits when the selected items are reconstructed
+pub struct FptasKnapsackSolver {
+    epsilon: f64, // The allowed relative error, must be in the range (0, 1)
+}
+
+impl FptasKnapsackSolver {
+    /// Creates a new `FptasKnapsackSolver` with the given approximation parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - The allowed relative error. Smaller values give more precise results
+    ///   at the cost of a larger dynamic programming table.
+    ///
+    /// # Returns
+    ///
+    /// A new `FptasKnapsackSolver` instance with the specified epsilon.
+    pub fn new(epsilon: f64) -> Self {
+        Self { epsilon }
+    }
+
+    /// Gets the approximation parameter of the solver.
+    ///
+    /// # Returns
+    ///
+    /// The epsilon of the solver as a `f64`.
+    pub fn get_epsilon(&self) -> f64 {
+        self.epsilon
+    }
+}
+
+impl KnapsackSolver for FptasKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name with its epsilon, e.g. "FPTAS 0.1".
+    fn get_name(&self) -> String {
+        format!("FPTAS {}", self.epsilon)
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "fptas_<epsilon>", e.g. "fptas_0_1".
+    fn get_id(&self) -> String {
+        name_to_id(&format!("fptas {}", self.epsilon))
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Approximate(1.0 - self.epsilon), "O(n^2 / ε)", "O(n^3 / (64ε))")
+    }
+
+    /// Solves the knapsack problem approximately.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value of the selected items, which is at least `(1 - ε)` of the optimal value.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.scaled_dynamic(knapsack, false).map(|(value, _)| value)
+    }
+
+    /// Solves the knapsack problem approximately and returns the selected items.
+    ///
+    /// The decisions of the dynamic programming are kept in an `n × P` bit table,
+    /// where `P` is the sum of the scaled values.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` whose value is at least `(1 - ε)` of the optimal value.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.scaled_dynamic(knapsack, true)
+            .map(|(_, items)| KnapsackSolution::new(knapsack, items))
+    }
+}
+
+impl FptasKnapsackSolver {
+    /// Runs the dynamic programming over the scaled values.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    /// * `track_items` - Whether to keep the decisions needed to restore the selected items.
+    ///
+    /// # Returns
+    /// * The value of the found set of items and its items (empty if they are not tracked).
+    fn scaled_dynamic(&self, knapsack: &Knapsack, track_items: bool) -> Result<(u64, Vec<usize>), KnapsackError> {
+        if !(self.epsilon > 0.0 && self.epsilon < 1.0) {
+            return Err(KnapsackError::InvalidParameter("epsilon must be in the range (0, 1)".to_string()));
+        }
+        knapsack.check_overflow()?;
+
+        let capacity = knapsack.get_capacity();
+
+        // Items that don't fit alone can't be part of any solution
+        let items: Vec<usize> = (0..knapsack.get_items_len())
+            .filter(|&i| knapsack.get_item(i).get_weight() <= capacity)
+            .collect();
+
+        let max_value = items.iter().map(|&i| knapsack.get_item(i).get_value()).max().unwrap_or(0);
+        if max_value == 0 {
+            return Ok((0, Vec::new()));
+        }
+
+        // Scaling factor; values are kept as is when they are already small enough
+        let scale = (self.epsilon * max_value as f64 / items.len() as f64).max(1.0);
+        let scaled: Vec<usize> = items
+            .iter()
+            .map(|&i| (knapsack.get_item(i).get_value() as f64 / scale).floor() as usize)
+            .collect();
+        let profit_sum: usize = scaled.iter().sum();
+
+        let words = if track_items { profit_sum / 64 + 1 } else { 0 };
+        check_decisions_size(items.len(), words)?;
+
+        // best[p] = (minimum weight, real value) of a set of items with scaled value p.
+        // Every state corresponds to an actual set of items, so the real value of any
+        // reachable state is a valid answer.
+        let mut best: Vec<Option<(u64, u64)>> = vec![None; profit_sum + 1];
+        best[0] = Some((0, 0));
+        // taken[k] is a bitset over scaled values: whether items[k] was added to the state
+        let mut taken = vec![vec![0u64; words]; items.len()];
+
+        for (k, (&i, &profit)) in items.iter().zip(scaled.iter()).enumerate() {
+            let weight = knapsack.get_item(i).get_weight();
+            let value = knapsack.get_item(i).get_value();
+
+            for p in (profit..=profit_sum).rev() {
+                if let Some((prev_weight, prev_value)) = best[p - profit] {
+                    if capacity - prev_weight < weight {
+                        continue;
+                    }
+                    let candidate = (prev_weight + weight, prev_value + value);
+                    // Prefer lighter sets, and more valuable ones among equally heavy
+                    let is_better = match best[p] {
+                        Some((current_weight, current_value)) => {
+                            candidate.0 < current_weight
+                                || (candidate.0 == current_weight && candidate.1 > current_value)
+                        }
+                        None => true,
+                    };
+                    if is_better {
+                        best[p] = Some(candidate);
+                        if track_items {
+                            taken[k][p / 64] |= 1 << (p % 64);
+                        }
+                    }
+                }
+            }
+        }
+
+        let (mut p, value) = best
+            .iter()
+            .enumerate()
+            .filter_map(|(p, state)| state.map(|(_, value)| (p, value)))
+            .max_by_key(|&(_, value)| value)
+            .unwrap_or((0, 0));
+
+        if !track_items {
+            return Ok((value, Vec::new()));
+        }
+
+        // Walk the decisions back from the last item
+        let mut selected = Vec::new();
+        for k in (0..items.len()).rev() {
+            if taken[k][p / 64] & (1 << (p % 64)) != 0 {
+                selected.push(items[k]);
+                p -= scaled[k];
+            }
+        }
+
+        Ok((value, selected))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::fractional_knapsack_solution::FractionalKnapsackSolution;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use std::cmp::Ordering;
+
+/// Solver of the fractional (continuous) knapsack problem.
+///
+/// Any fraction of an item may be taken, so the optimum takes the items with the best
+/// value-to-weight ratios entirely and a part of the critical item. Instead of sorting,
+/// the critical ratio is found by repeatedly partitioning the items around a random pivot ratio
+/// and keeping only the part which contains the critical item.
+/// Time complexity: O(n) expected
+/// Space complexity: O(n)
+pub struct FractionalKnapsackSolver;
+
+impl FractionalKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Fractional".
+    pub fn get_name(&self) -> String {
+        "Fractional".to_string()
+    }
+
+    /// Solves the fractional knapsack problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `FractionalKnapsackSolution` with the entirely taken items and the critical item,
+    /// or `KnapsackError::ArithmeticOverflow` if the total value of the fitting items doesn't fit in `u64`.
+    pub fn solve(&self, knapsack: &Knapsack) -> Result<FractionalKnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+        let mut taken = Vec::new();
+        let mut candidates = Vec::with_capacity(knapsack.get_items_len());
+
+        for i in 0..knapsack.get_items_len() {
+            let (weight, value) = pair(i);
+            if value == 0 {
+                // Such items never improve a solution
+                continue;
+            }
+            if weight == 0 {
+                // Such items are always taken
+                taken.push(i);
+                continue;
+            }
+            candidates.push(i);
+        }
+
+        let mut remaining = knapsack.get_capacity();
+        let mut seed = candidates.len() as u64;
+
+        while !candidates.is_empty() {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let pivot = pair(candidates[(seed >> 33) as usize % candidates.len()]);
+
+            let mut greater = Vec::new();
+            let mut equal = Vec::new();
+            let mut less = Vec::new();
+            for i in candidates {
+                match compare_ratios(pair(i), pivot) {
+                    Ordering::Greater => greater.push(i),
+                    Ordering::Equal => equal.push(i),
+                    Ordering::Less => less.push(i),
+                }
+            }
+
+            // The critical item has a better ratio than the pivot
+            let greater_weight: u128 = greater.iter().map(|&i| pair(i).0 as u128).sum();
+            if greater_weight > remaining as u128 {
+                candidates = greater;
+                continue;
+            }
+
+            remaining -= greater_weight as u64;
+            taken.extend(greater);
+
+            for i in equal {
+                let weight = pair(i).0;
+                if weight > remaining {
+                    return Ok(FractionalKnapsackSolution::new(knapsack, taken, Some((i, remaining))));
+                }
+                remaining -= weight;
+                taken.push(i);
+            }
+
+            candidates = less;
+        }
+
+        Ok(FractionalKnapsackSolution::new(knapsack, taken, None))
+    }
+}
@@ -1,8 +1,19 @@
+use crate::models::knapsack_error::KnapsackError;
 use crate::models::knapsack_solver::KnapsackSolver;
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::solve_context::{SolveContext, StopCheck};
+use crate::models::solve_observer::SolveEvent;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
 
 pub struct BitMaskKnapsackSolver;
 
+impl BitMaskKnapsackSolver {
+    /// The largest supported number of items: the masks of all subsets are counted by a `u64`.
+    pub const MAX_ITEMS: usize = 63;
+}
+
 /// A solver for the knapsack problem using bit masks.
 impl KnapsackSolver for BitMaskKnapsackSolver {
     /// Returns the name of the algorithm.
@@ -14,6 +25,24 @@ impl KnapsackSolver for BitMaskKnapsackSolver {
         "Bit mask".to_string()
     }
 
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "bit_mask".
+    fn get_id(&self) -> String {
+        "bit_mask".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(2^n * n)", "O(n)").with_max_items(Self::MAX_ITEMS)
+    }
+
     /// Solves the knapsack problem using bit masks.
     ///
     /// This method attempts all possible combinations of items to find the maximum value
@@ -27,39 +56,80 @@ impl KnapsackSolver for BitMaskKnapsackSolver {
     ///
     /// A `u64` value representing the maximum value that can be achieved by fitting items
     /// in the knapsack without exceeding the capacity.
-    fn solve(&self, knapsack: &Knapsack) -> Result<u64, String> {
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    /// Solves the knapsack problem using bit masks and returns the selected items.
+    ///
+    /// The mask of the best combination is remembered, so the items are restored from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        Ok(self.solve_with_context(knapsack, &SolveContext::new())?.into_solution())
+    }
+
+    /// Solves the knapsack problem using bit masks until the context stops the solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    /// * `context` - The deadline and the cancellation token of the solver.
+    ///
+    /// # Returns
+    ///
+    /// A `SolveOutcome` with the best combination found, which isn't proven if the solver was stopped.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        knapsack.check_overflow()?;
         let item_count = knapsack.get_items_len();
         let mut best_value = 0;
+        let mut best_mask = 0;
 
-        if knapsack.get_items_len() > 64 {
-            return Err("The number of items exceeds the maximum allowed (64).".to_string());
+        if item_count > Self::MAX_ITEMS {
+            return Err(KnapsackError::TooManyItems { items: item_count, max: Self::MAX_ITEMS });
         }
 
+        let mut stop = StopCheck::new(context);
         // Iterate over all possible combinations of items represented by bit masks
         for mask in 0..(1u64 << item_count) {
+            if stop.should_stop() {
+                break;
+            }
             let mut current_weight = 0;
             let mut current_value = 0;
+            let mut fits = true;
 
             // Iterate over each bit in the mask to determine whether to include the corresponding item
             for i in 0..item_count {
                 if (mask & (1 << i)) != 0 {
                     let item = knapsack.get_item(i);
-                    current_weight += item.get_weight();
-                    current_value += item.get_value();
 
-                    // If the current weight exceeds the capacity, skip this combination
-                    if current_weight > knapsack.get_capacity() {
+                    // If the current weight exceeds the capacity, skip this combination.
+                    // Written as a subtraction so that the sum of weights can't overflow
+                    if item.get_weight() > knapsack.get_capacity() - current_weight {
+                        fits = false;
                         break;
                     }
+                    current_weight += item.get_weight();
+                    current_value += item.get_value();
                 }
             }
 
             // Update the best value if the current combination is valid and better
-            if current_weight <= knapsack.get_capacity() {
-                best_value = best_value.max(current_value);
+            if fits && current_value > best_value {
+                best_value = current_value;
+                best_mask = mask;
+                stop.notify(SolveEvent::IncumbentFound { value: current_value });
             }
         }
 
-        Ok(best_value)
+        let items = (0..item_count).filter(|&i| best_mask & (1 << i) != 0).collect();
+        Ok(SolveOutcome::new(KnapsackSolution::new(knapsack, items), !stop.is_stopped()))
     }
 }
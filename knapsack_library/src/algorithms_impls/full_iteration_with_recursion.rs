@@ -1,5 +1,11 @@
+use crate::models::knapsack_error::KnapsackError;
 use crate::models::knapsack_solver::KnapsackSolver;
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::solve_context::{SolveContext, StopCheck};
+use crate::models::solve_observer::SolveEvent;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
 
 pub struct RecursiveKnapsackSolver;
 
@@ -14,6 +20,24 @@ impl KnapsackSolver for RecursiveKnapsackSolver {
         "Recursion".to_string()
     }
 
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "recursion".
+    fn get_id(&self) -> String {
+        "recursion".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(2^n)", "O(n)")
+    }
+
     /// Solves the knapsack problem using recursion.
     ///
     /// This method attempts all possible combinations of items to find the maximum value
@@ -27,61 +51,95 @@ impl KnapsackSolver for RecursiveKnapsackSolver {
     ///
     /// A `u64` value representing the maximum value that can be achieved by fitting items
     /// in the knapsack without exceeding the capacity.
-    fn solve(&self, knapsack: &Knapsack) -> Result<u64, String> {
-        let mut best_value = 0; // Keeps track of the best (maximum) value found
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
 
-        /// A recursive helper function to explore all possible item combinations.
-        ///
-        /// # Arguments
-        ///
-        /// * `knapsack` - A reference to the `Knapsack` object.
-        /// * `index` - The index of the current item being considered.
-        /// * `current_weight` - The total weight of the selected items so far.
-        /// * `current_value` - The total value of the selected items so far.
-        /// * `best_value` - A mutable reference to store the best value found.
-        fn recursive(
-            knapsack: &Knapsack,
-            index: usize,
-            current_weight: u64,
-            current_value: u64,
-            best_value: &mut u64,
-        ) {
-            // Base case: if we've gone through all the items, stop recursion
-            if index > knapsack.get_items_len() {
-                return;
-            }
+    /// Solves the knapsack problem using recursion and returns the selected items.
+    ///
+    /// The path of taken items is kept during the recursion, and the best one is remembered.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        Ok(self.solve_with_context(knapsack, &SolveContext::new())?.into_solution())
+    }
 
-            // If we've considered all items, check if the current value is the best so far
-            if index == knapsack.get_items_len() {
-                if current_value > *best_value {
-                    *best_value = current_value; // Update the best value
-                }
-                return;
-            }
+    /// Solves the knapsack problem using recursion until the context stops the solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    /// * `context` - The deadline and the cancellation token of the solver.
+    ///
+    /// # Returns
+    ///
+    /// A `SolveOutcome` with the best combination found, which isn't proven if the solver was stopped.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        knapsack.check_overflow()?;
+        let mut recursion = Recursion {
+            knapsack,
+            current_path: Vec::new(),
+            best_value: 0,
+            best_path: Vec::new(),
+            stop: StopCheck::new(context),
+        };
 
-            // Case 1: Don't take the item at the current index
-            recursive(
-                knapsack,
-                index + 1,
-                current_weight,
-                current_value,
-                best_value,
-            );
+        // Start the recursion with initial values (starting from the first item)
+        recursion.recursive(0, 0, 0);
+        let proven = !recursion.stop.is_stopped();
+        Ok(SolveOutcome::new(KnapsackSolution::new(knapsack, recursion.best_path), proven))
+    }
+}
 
-            // Case 2: Take the item at the current index (if it fits in the knapsack)
-            if current_weight + knapsack.get_item(index).get_weight() <= knapsack.get_capacity() {
-                recursive(
-                    knapsack,
-                    index + 1,
-                    current_weight + knapsack.get_item(index).get_weight(),
-                    current_value + knapsack.get_item(index).get_value(),
-                    best_value,
-                );
+/// State of the recursion over all item combinations.
+struct Recursion<'a> {
+    knapsack: &'a Knapsack,  // The knapsack being solved
+    current_path: Vec<usize>, // Items taken on the current branch
+    best_value: u64,          // Keeps track of the best (maximum) value found
+    best_path: Vec<usize>,    // Items taken in the best combination
+    stop: StopCheck<'a>,      // Checks whether the solver must stop
+}
+
+impl Recursion<'_> {
+    /// A recursive helper function to explore all possible item combinations.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the current item being considered.
+    /// * `current_weight` - The total weight of the selected items so far.
+    /// * `current_value` - The total value of the selected items so far.
+    fn recursive(&mut self, index: usize, current_weight: u64, current_value: u64) {
+        // Base case: if we've gone through all the items or the solver was stopped, stop recursion
+        if index > self.knapsack.get_items_len() || self.stop.should_stop() {
+            return;
+        }
+
+        // If we've considered all items, check if the current value is the best so far
+        if index == self.knapsack.get_items_len() {
+            if current_value > self.best_value {
+                self.best_value = current_value; // Update the best value
+                self.best_path.clone_from(&self.current_path);
+                self.stop.notify(SolveEvent::IncumbentFound { value: current_value });
             }
+            return;
         }
 
-        // Start the recursion with initial values (starting from the first item)
-        recursive(knapsack, 0, 0, 0, &mut best_value);
-        Ok(best_value)
+        // Case 1: Don't take the item at the current index
+        self.recursive(index + 1, current_weight, current_value);
+
+        // Case 2: Take the item at the current index (if it fits in the knapsack)
+        let item = self.knapsack.get_item(index);
+        // Written as a subtraction so that the sum of weights can't overflow
+        if item.get_weight() <= self.knapsack.get_capacity() - current_weight {
+            self.current_path.push(index);
+            self.recursive(index + 1, current_weight + item.get_weight(), current_value + item.get_value());
+            self.current_path.pop();
+        }
     }
 }
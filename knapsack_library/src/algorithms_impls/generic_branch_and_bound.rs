@@ -0,0 +1,123 @@
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::generic_knapsack_solution::GenericKnapsackSolution;
+use crate::models::generic_knapsack_solver::GenericKnapsackSolver;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::numeric::Numeric;
+
+/// Branch and bound implementation of the generic Knapsack solver.
+///
+/// This solver sorts the items by their value-to-weight ratio and explores the decision tree
+/// depth-first, pruning subtrees with the Dantzig fractional bound. It works with any `Numeric` type;
+/// for floating-point data the result is optimal up to rounding errors.
+/// Time complexity: O(2^n * n) in the worst case, but much faster on most instances.
+/// Space complexity: O(n)
+pub struct GenericBranchAndBoundKnapsackSolver;
+
+impl<T: Numeric> GenericKnapsackSolver<T> for GenericBranchAndBoundKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Generic branch and bound".
+    fn get_name(&self) -> String {
+        "Generic branch and bound".to_string()
+    }
+
+    /// Solves the knapsack problem using branch and bound and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `GenericKnapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `GenericKnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &GenericKnapsack<T>) -> Result<GenericKnapsackSolution<T>, KnapsackError> {
+        knapsack.validate()?;
+        let capacity = knapsack.get_capacity();
+        let mut base_items = Vec::new();
+        let mut indices = Vec::with_capacity(knapsack.get_items_len());
+
+        for i in 0..knapsack.get_items_len() {
+            let item = knapsack.get_item(i);
+            if item.get_value() == T::zero() || item.get_weight() > capacity {
+                // Such items never improve a solution
+                continue;
+            }
+            if item.get_weight() == T::zero() {
+                // Such items are always taken
+                base_items.push(i);
+                continue;
+            }
+            indices.push(i);
+        }
+
+        // Sort by value-to-weight ratio (descending order)
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+        indices.sort_by(|&a, &b| T::compare_ratios(pair(b), pair(a)));
+        let items: Vec<(T, T)> = indices.iter().map(|&i| pair(i)).collect();
+
+        let mut search = Search { items: &items, path: Vec::new(), best_path: Vec::new(), best_value: T::zero() };
+        search.explore(0, capacity, T::zero());
+
+        base_items.extend(search.best_path.iter().map(|&k| indices[k]));
+        Ok(GenericKnapsackSolution::new(knapsack, base_items))
+    }
+}
+
+/// State of the depth-first search over items sorted by value-to-weight ratio.
+struct Search<'a, T> {
+    items: &'a [(T, T)],   // Items sorted by decreasing value-to-weight ratio
+    path: Vec<usize>,      // Positions of the items taken on the current branch
+    best_path: Vec<usize>, // Positions of the items of the best solution found
+    best_value: T,         // The value of the best solution found
+}
+
+impl<T: Numeric> Search<'_, T> {
+    /// Depth-first exploration of the decision tree.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the current item being considered.
+    /// * `remaining` - The remaining capacity of the knapsack.
+    /// * `current_value` - The total value of the selected items so far.
+    fn explore(&mut self, index: usize, remaining: T, current_value: T) {
+        if current_value > self.best_value {
+            self.best_value = current_value;
+            self.best_path.clone_from(&self.path);
+        }
+
+        if index == self.items.len() {
+            return;
+        }
+
+        // Prune the subtree if it can't contain a better solution
+        if current_value + self.dantzig_bound(index, remaining) <= self.best_value {
+            return;
+        }
+
+        // Case 1: Take the item at the current index (if it fits in the knapsack)
+        let (weight, value) = self.items[index];
+        if weight <= remaining {
+            self.path.push(index);
+            self.explore(index + 1, remaining - weight, current_value + value);
+            self.path.pop();
+        }
+
+        // Case 2: Don't take the item at the current index
+        self.explore(index + 1, remaining, current_value);
+    }
+
+    /// Computes the value of the fractional knapsack of the items starting at `index`,
+    /// which bounds the value the subtree can add.
+    fn dantzig_bound(&self, index: usize, mut remaining: T) -> T {
+        let mut bound = T::zero();
+        for &(weight, value) in &self.items[index..] {
+            if weight > remaining {
+                return bound + T::fractional_value(value, weight, remaining);
+            }
+            remaining = remaining - weight;
+            bound = bound + value;
+        }
+        bound
+    }
+}
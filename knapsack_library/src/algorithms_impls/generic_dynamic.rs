@@ -0,0 +1,66 @@
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::generic_knapsack_solution::GenericKnapsackSolution;
+use crate::models::generic_knapsack_solver::GenericKnapsackSolver;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::numeric::Integer;
+use crate::models::solver_capabilities::{check_decisions_size, MAX_TABLE_CELLS};
+
+/// Dynamic Programming implementation of the generic Knapsack solver
+///
+/// This solver fills the same table as `DynamicKnapsackSolver`, but stores values of the type `T`,
+/// so `u32` instances take half the memory. The table is indexed by capacity, so the solver
+/// is only implemented for `Integer` types.
+/// Time complexity: O(nW) where n is the number of items and W is the capacity
+/// Space complexity: O(W) values and O(nW) bits, the table may have at most `MAX_TABLE_CELLS` cells
+pub struct GenericDynamicKnapsackSolver;
+
+impl<T: Integer> GenericKnapsackSolver<T> for GenericDynamicKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Generic dynamic".to_string()
+    }
+
+    fn solve_with_items(&self, knapsack: &GenericKnapsack<T>) -> Result<GenericKnapsackSolution<T>, KnapsackError> {
+        knapsack.validate()?;
+        let n = knapsack.get_items_len();
+        let capacity = match knapsack.get_capacity().to_usize() {
+            Some(capacity) if (capacity as u64) < MAX_TABLE_CELLS => capacity,
+            _ => return Err(KnapsackError::CapacityTooLarge),
+        };
+        let words = capacity / 64 + 1;
+        check_decisions_size(n, words)?;
+
+        let mut prev = vec![T::zero(); capacity + 1];
+        let mut curr = vec![T::zero(); capacity + 1];
+        // taken[i] is a bitset over capacities: whether item i is taken in the best solution for w
+        let mut taken = vec![vec![0u64; words]; n];
+
+        for (i, row) in taken.iter_mut().enumerate() {
+            let item = knapsack.get_item(i);
+            // Items heavier than the capacity are never taken
+            let weight = item.get_weight().to_usize().unwrap_or(usize::MAX);
+            let value = item.get_value();
+
+            for w in 0..=capacity {
+                curr[w] = if weight <= w && prev[w - weight] + value > prev[w] {
+                    row[w / 64] |= 1 << (w % 64);
+                    prev[w - weight] + value
+                } else {
+                    prev[w]
+                };
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+
+        // Walk the decisions back from the last item
+        let mut items = Vec::new();
+        let mut w = capacity;
+        for i in (0..n).rev() {
+            if taken[i][w / 64] & (1 << (w % 64)) != 0 {
+                items.push(i);
+                w -= knapsack.get_item(i).get_weight().to_usize().unwrap();
+            }
+        }
+
+        Ok(GenericKnapsackSolution::new(knapsack, items))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::generic_knapsack_solution::GenericKnapsackSolution;
+use crate::models::generic_knapsack_solver::GenericKnapsackSolver;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::numeric::Numeric;
+
+/// Greedy implementation of the generic Knapsack solver.
+///
+/// This solver takes the items with zero weight and then the other items in the order
+/// of decreasing value-to-weight ratio while they fit. It works with any `Numeric` type,
+/// including floating-point weights and values.
+/// Time complexity: O(n log n)
+/// Space complexity: O(n)
+pub struct GenericGreedyKnapsackSolver;
+
+impl<T: Numeric> GenericKnapsackSolver<T> for GenericGreedyKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Generic greedy".
+    fn get_name(&self) -> String {
+        "Generic greedy".to_string()
+    }
+
+    /// Solves the knapsack problem using a greedy heuristic and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `GenericKnapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `GenericKnapsackSolution` with the greedily selected items.
+    fn solve_with_items(&self, knapsack: &GenericKnapsack<T>) -> Result<GenericKnapsackSolution<T>, KnapsackError> {
+        knapsack.validate()?;
+        let capacity = knapsack.get_capacity();
+        let (mut selected, mut indices): (Vec<usize>, Vec<usize>) =
+            (0..knapsack.get_items_len()).partition(|&i| knapsack.get_item(i).get_weight() == T::zero());
+
+        // Sort by value-to-weight ratio (descending order)
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+        indices.sort_by(|&a, &b| T::compare_ratios(pair(b), pair(a)));
+
+        let mut current_weight = T::zero();
+        for i in indices {
+            let weight = knapsack.get_item(i).get_weight();
+            // Written as a subtraction so that the sum of weights can't overflow
+            if weight <= capacity - current_weight {
+                current_weight = current_weight + weight;
+                selected.push(i);
+            }
+        }
+
+        Ok(GenericKnapsackSolution::new(knapsack, selected))
+    }
+}
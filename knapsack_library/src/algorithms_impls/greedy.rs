@@ -1,5 +1,9 @@
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::knapsack_error::KnapsackError;
 use crate::models::knapsack_solver::KnapsackSolver;
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
 
 pub struct GreedyKnapsackSolver;
 
@@ -14,11 +18,29 @@ impl KnapsackSolver for GreedyKnapsackSolver {
         "Greedy".to_string()
     }
 
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "greedy".
+    fn get_id(&self) -> String {
+        "greedy".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Heuristic, "O(n log n)", "O(n)")
+    }
+
     /// Solves the knapsack problem using a greedy heuristic.
     ///
-    /// This method calculates the value-to-weight ratio for each item, sorts the items
-    /// in descending order based on that ratio, and then iteratively adds items to the
-    /// knapsack as long as the capacity is not exceeded.
+    /// This method takes the items without weight, sorts the other items in descending order
+    /// of their value-to-weight ratio, and then iteratively adds items to the knapsack as long
+    /// as the capacity is not exceeded.
     ///
     /// # Arguments
     ///
@@ -27,32 +49,52 @@ impl KnapsackSolver for GreedyKnapsackSolver {
     /// # Returns
     ///
     /// A `u64` value representing the total value of the selected items.
-    fn solve(&self, knapsack: &Knapsack) -> Result<u64, String> {
-        // Create a vector of item indices.
-        let mut indices: Vec<usize> = (0..knapsack.get_items_len()).collect();
-
-        // Sort indices based on the value-to-weight ratio (descending order).
-        indices.sort_by(|&i, &j| {
-            let item_i = knapsack.get_item(i);
-            let item_j = knapsack.get_item(j);
-            let ratio_i = item_i.get_value() as f64 / item_i.get_weight() as f64;
-            let ratio_j = item_j.get_value() as f64 / item_j.get_weight() as f64;
-            // Compare ratios in reverse to get descending order.
-            ratio_j.partial_cmp(&ratio_i).unwrap()
-        });
-
-        let mut current_weight = 0;
-        let mut total_value = 0;
-
-        // Greedily add items while the capacity is not exceeded.
-        for i in indices {
-            let item = knapsack.get_item(i);
-            if current_weight + item.get_weight() <= knapsack.get_capacity() {
-                current_weight += item.get_weight();
-                total_value += item.get_value();
-            }
-        }
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
 
-        Ok(total_value)
+    /// Solves the knapsack problem using a greedy heuristic and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the greedily selected items.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        Ok(KnapsackSolution::new(knapsack, greedy_items(knapsack)))
+    }
+}
+
+/// Selects the items of the greedy solution.
+///
+/// Items without value are never taken, since they can't improve the solution, and items without weight
+/// are always taken. The other items are taken in the order of decreasing value-to-weight ratio while they fit;
+/// the ratios are compared exactly by cross-multiplication, and ties keep the order of the items.
+///
+/// # Arguments
+/// * `knapsack` - Reference to the Knapsack instance.
+///
+/// # Returns
+/// * Indices of the selected items.
+pub(crate) fn greedy_items(knapsack: &Knapsack) -> Vec<usize> {
+    let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+    let (mut selected, mut indices): (Vec<usize>, Vec<usize>) =
+        (0..knapsack.get_items_len()).filter(|&i| pair(i).1 > 0).partition(|&i| pair(i).0 == 0);
+
+    // Sort by value-to-weight ratio (descending order); the sort is stable
+    indices.sort_by(|&a, &b| compare_ratios(pair(b), pair(a)));
+
+    let mut current_weight = 0;
+    for i in indices {
+        let weight = pair(i).0;
+        // Written as a subtraction so that the sum of weights can't overflow
+        if weight <= knapsack.get_capacity() - current_weight {
+            current_weight += weight;
+            selected.push(i);
+        }
     }
+    selected
 }
@@ -1,5 +1,10 @@
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
 use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::{SolveContext, StopCheck};
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities, MAX_TABLE_CELLS};
 use std::collections::HashMap;
 
 /// Lazy Dynamic Programming implementation of the Knapsack solver.
@@ -14,12 +19,21 @@ impl KnapsackSolver for LazyDynamicKnapsackSolver {
         "Lazy Dynamic".to_string()
     }
 
-    fn solve(&self, knapsack: &Knapsack) -> Result<u64, String> {
+    fn get_id(&self) -> String {
+        "lazy_dynamic".to_string()
+    }
+
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(nW)", "O(nW)").with_max_capacity(MAX_TABLE_CELLS - 1)
+    }
+
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        knapsack.check_overflow()?;
         let n = knapsack.get_items_len();
         let capacity = knapsack.get_capacity();
 
         if capacity >= usize::MAX as u64 {
-            return Err("Capacity too large to process".to_string());
+            return Err(KnapsackError::CapacityTooLarge);
         }
         let capacity = capacity as usize;
 
@@ -32,7 +46,44 @@ impl KnapsackSolver for LazyDynamicKnapsackSolver {
         let mut memo = HashMap::new();
 
         // Call recursive implementation and return
-        Ok(Self::knapsack_recursive(n, capacity, knapsack, &mut memo))
+        let context = SolveContext::new();
+        Ok(Self::knapsack_recursive(n, capacity, knapsack, &mut memo, &mut StopCheck::new(&context)))
+    }
+
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        Ok(self.solve_with_context(knapsack, &SolveContext::new())?.into_solution())
+    }
+
+    // Every computed state counts as an explored node. The cached values are incomplete
+    // when the context stops the solver, so a stopped solver returns `Cancelled`.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        knapsack.check_overflow()?;
+        let capacity = knapsack.get_capacity();
+
+        if capacity >= usize::MAX as u64 {
+            return Err(KnapsackError::CapacityTooLarge);
+        }
+
+        let mut memo = HashMap::new();
+        let mut stop = StopCheck::new(context);
+        let mut items = Vec::new();
+        let mut w = capacity as usize;
+
+        // Item i is taken if the best value changes without it.
+        // Missing states are computed on demand and stored in the same cache.
+        for i in (1..=knapsack.get_items_len()).rev() {
+            let with_item = Self::knapsack_recursive(i, w, knapsack, &mut memo, &mut stop);
+            let without_item = Self::knapsack_recursive(i - 1, w, knapsack, &mut memo, &mut stop);
+            if stop.is_stopped() {
+                return Err(KnapsackError::Cancelled);
+            }
+            if with_item != without_item {
+                items.push(i - 1);
+                w -= knapsack.get_item(i - 1).get_weight() as usize;
+            }
+        }
+
+        Ok(SolveOutcome::new(KnapsackSolution::new(knapsack, items), true))
     }
 }
 
@@ -44,14 +95,16 @@ impl LazyDynamicKnapsackSolver {
     /// * `w` - Remaining capacity.
     /// * `knapsack` - Reference to the Knapsack instance.
     /// * `memo` - Mutable reference to a memoization cache.
+    /// * `stop` - Checks whether the solver must stop.
     ///
     /// # Returns
-    /// * Maximum value that can be achieved with the given parameters.
+    /// * Maximum value that can be achieved with the given parameters, meaningless if the solver was stopped.
     fn knapsack_recursive(
         i: usize,
         w: usize,
         knapsack: &Knapsack,
         memo: &mut HashMap<(usize, usize), u64>,
+        stop: &mut StopCheck,
     ) -> u64 {
         // If no items or capacity =>
         if i == 0 || w == 0 {
@@ -64,22 +117,29 @@ impl LazyDynamicKnapsackSolver {
             return cached;
         }
 
+        // Nothing is cached once the solver is stopped
+        if stop.should_stop() {
+            return 0;
+        }
+
         // Zero-based index
         let item = knapsack.get_item(i - 1);
 
         // Recursive call
-        let mut result = Self::knapsack_recursive(i - 1, w, knapsack, memo);
+        let mut result = Self::knapsack_recursive(i - 1, w, knapsack, memo, stop);
 
         // Try to put item in current result
         if item.get_weight() as usize <= w {
             let value_with_item =
-                Self::knapsack_recursive(i - 1, w - item.get_weight() as usize, knapsack, memo)
+                Self::knapsack_recursive(i - 1, w - item.get_weight() as usize, knapsack, memo, stop)
                     + item.get_value();
             result = result.max(value_with_item);
         }
 
-        // Store the value and return it
-        memo.insert((i, w), result);
+        // Store the value and return it, unless the solver was stopped while computing it
+        if !stop.is_stopped() {
+            memo.insert((i, w), result);
+        }
         result
     }
 }
@@ -0,0 +1,174 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
+
+/// Meet in the middle implementation of the Knapsack solver.
+///
+/// This solver splits the items into two halves and enumerates the subset sums of each half.
+/// Dominated subsets of the second half are removed and both halves are merged with a sorted sweep.
+/// Time complexity: O(2^(n/2) * n)
+/// Space complexity: O(2^(n/2))
+pub struct MeetInTheMiddleKnapsackSolver;
+
+impl MeetInTheMiddleKnapsackSolver {
+    /// The largest supported number of items: the subset sums of both halves still fit in memory.
+    pub const MAX_ITEMS: usize = 48;
+}
+
+impl KnapsackSolver for MeetInTheMiddleKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Meet in the middle".
+    fn get_name(&self) -> String {
+        "Meet in the middle".to_string()
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "meet_in_the_middle".
+    fn get_id(&self) -> String {
+        "meet_in_the_middle".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(2^(n/2) * n)", "O(2^(n/2))").with_max_items(Self::MAX_ITEMS)
+    }
+
+    /// Solves the knapsack problem using the meet in the middle technique.
+    ///
+    /// The running time does not depend on the capacity, so this solver can be used
+    /// for instances where the dynamic programming table cannot be allocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value representing the maximum value that can be achieved by fitting items
+    /// in the knapsack without exceeding the capacity.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    /// Solves the knapsack problem using the meet in the middle technique and returns the selected items.
+    ///
+    /// Every subset sum keeps the mask of its items within the half, so the items are restored from
+    /// the masks of the best pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        let item_count = knapsack.get_items_len();
+
+        if item_count > Self::MAX_ITEMS {
+            return Err(KnapsackError::TooManyItems { items: knapsack.get_items_len(), max: Self::MAX_ITEMS });
+        }
+
+        let middle = item_count / 2;
+        let mut left = Self::subset_sums(knapsack, 0, middle);
+        let right = Self::remove_dominated(Self::subset_sums(knapsack, middle, item_count));
+
+        left.sort_unstable();
+
+        // Sweep the left half by increasing weight, moving the pointer over the
+        // right half towards lighter subsets as the remaining capacity shrinks.
+        let capacity = knapsack.get_capacity();
+        let mut best_value = 0;
+        let mut best_masks = (0, 0);
+        let mut j = right.len();
+
+        for (weight, value, mask) in left {
+            let remaining = capacity - weight;
+            while j > 0 && right[j - 1].0 > remaining {
+                j -= 1;
+            }
+            if j == 0 {
+                break;
+            }
+            if value + right[j - 1].1 > best_value {
+                best_value = value + right[j - 1].1;
+                best_masks = (mask, right[j - 1].2);
+            }
+        }
+
+        let items = (0..middle)
+            .filter(|&i| best_masks.0 & (1 << i) != 0)
+            .chain((middle..item_count).filter(|&i| best_masks.1 & (1 << (i - middle)) != 0))
+            .collect();
+        Ok(KnapsackSolution::new(knapsack, items))
+    }
+}
+
+impl MeetInTheMiddleKnapsackSolver {
+    /// Enumerates `(weight, value, mask)` triples of all subsets of `items[from..to]` that fit in the knapsack.
+    ///
+    /// Bit `i` of the mask is set if the item `from + i` belongs to the subset.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    /// * `from` - Index of the first item of the half (inclusive).
+    /// * `to` - Index of the last item of the half (exclusive).
+    ///
+    /// # Returns
+    /// * Vector of weight and value sums with masks, one entry per feasible subset.
+    fn subset_sums(knapsack: &Knapsack, from: usize, to: usize) -> Vec<(u64, u64, u64)> {
+        let capacity = knapsack.get_capacity();
+        let mut sums = vec![(0, 0, 0)];
+
+        for i in from..to {
+            let item = knapsack.get_item(i);
+            if item.get_weight() > capacity {
+                continue;
+            }
+
+            for k in 0..sums.len() {
+                let (weight, value, mask) = sums[k];
+                // Written as a subtraction so that the sum of weights can't overflow
+                if capacity - weight >= item.get_weight() {
+                    sums.push((weight + item.get_weight(), value + item.get_value(), mask | 1 << (i - from)));
+                }
+            }
+        }
+
+        sums
+    }
+
+    /// Removes dominated subsets, i.e. subsets that are not lighter than some subset with a higher value.
+    ///
+    /// # Arguments
+    /// * `sums` - Weight and value sums of subsets with their masks.
+    ///
+    /// # Returns
+    /// * Subsets sorted by weight with strictly increasing values.
+    fn remove_dominated(mut sums: Vec<(u64, u64, u64)>) -> Vec<(u64, u64, u64)> {
+        // Equal weights are ordered by decreasing value, so the first one of them is kept
+        sums.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+        let mut result: Vec<(u64, u64, u64)> = Vec::with_capacity(sums.len());
+        for (weight, value, mask) in sums {
+            if result.last().is_none_or(|&(_, best, _)| value > best) {
+                result.push((weight, value, mask));
+            }
+        }
+
+        result
+    }
+}
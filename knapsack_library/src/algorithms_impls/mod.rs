@@ -1,5 +1,30 @@
 pub mod full_iteration_with_recursion;
 pub mod dynamic;
+pub mod parallel_dynamic;
+pub mod subset_sum;
 pub mod lazy_dynamic;
 pub mod full_iteration_with_bit_mask;
-pub mod greedy;
\ No newline at end of file
+pub mod greedy;
+pub mod extended_greedy;
+pub mod fractional;
+pub mod meet_in_the_middle;
+pub mod fptas;
+pub mod branch_and_bound;
+mod ratio;
+pub mod bounds;
+pub mod bounded_dynamic;
+pub mod profit_dynamic;
+pub mod auto;
+pub mod unbounded_dynamic;
+pub mod unbounded_branch_and_bound;
+mod unbounded_reduction;
+pub mod preprocessing;
+pub mod preprocessed;
+pub mod multi_dimensional_greedy;
+pub mod multi_dimensional_branch_and_bound;
+pub mod multi_dimensional_dynamic;
+pub mod multiple_sequential;
+pub mod multiple_branch_and_bound;
+pub mod generic_greedy;
+pub mod generic_branch_and_bound;
+pub mod generic_dynamic;
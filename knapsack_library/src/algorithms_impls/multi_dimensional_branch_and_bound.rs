@@ -0,0 +1,126 @@
+use crate::algorithms_impls::multi_dimensional_greedy::{aggregated_weight, sort_by_efficiency, MultiDimensionalGreedyKnapsackSolver};
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solution::MultiDimensionalKnapsackSolution;
+use crate::models::multi_dimensional_knapsack_solver::MultiDimensionalKnapsackSolver;
+
+/// Branch and bound implementation of the multi-dimensional Knapsack solver.
+///
+/// The capacity constraints are combined into one surrogate constraint `Σ_k w_k / c_k ≤ Σ_k r_k / c_k`,
+/// where `r_k` are the remaining capacities. Every solution of the original problem satisfies it, so the
+/// fractional (Dantzig) bound of the surrogate knapsack is an upper bound for the subtree. Items are
+/// explored depth-first in decreasing order of value per aggregated weight, and the incumbent is seeded
+/// with the greedy solution.
+/// Time complexity: O(2^n * n * d) in the worst case, but much faster on most instances.
+/// Space complexity: O(n * d)
+pub struct MultiDimensionalBranchAndBoundKnapsackSolver;
+
+impl MultiDimensionalKnapsackSolver for MultiDimensionalBranchAndBoundKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Multi-dimensional branch and bound".to_string()
+    }
+
+    fn solve(&self, knapsack: &MultiDimensionalKnapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &MultiDimensionalKnapsack) -> Result<MultiDimensionalKnapsackSolution, KnapsackError> {
+        let greedy = MultiDimensionalGreedyKnapsackSolver.solve_with_items(knapsack)?;
+
+        let order = sort_by_efficiency(knapsack);
+        let aggregated: Vec<f64> = order.iter().map(|&i| aggregated_weight(knapsack, i).unwrap_or(0.0)).collect();
+
+        let mut search = Search {
+            knapsack,
+            order: &order,
+            aggregated: &aggregated,
+            path: Vec::new(),
+            best_path: greedy.get_items().to_vec(),
+            best_value: greedy.get_total_value(),
+        };
+        let mut remaining = knapsack.get_capacities().to_vec();
+        search.explore(0, &mut remaining, 0);
+
+        Ok(MultiDimensionalKnapsackSolution::new(knapsack, search.best_path))
+    }
+}
+
+/// State of the depth-first search over items sorted by value per aggregated weight.
+struct Search<'a> {
+    knapsack: &'a MultiDimensionalKnapsack,
+    order: &'a [usize],     // Indices of the fitting items in decreasing order of efficiency
+    aggregated: &'a [f64],  // Aggregated weights of the items in `order`
+    path: Vec<usize>,       // Indices of the items taken on the current branch
+    best_path: Vec<usize>,  // Indices of the items of the best solution found
+    best_value: u64,        // The value of the best solution found
+}
+
+impl Search<'_> {
+    /// Depth-first exploration of the decision tree.
+    ///
+    /// # Arguments
+    /// * `index` - The position in `order` of the current item being considered.
+    /// * `remaining` - The remaining capacities of the knapsack.
+    /// * `current_value` - The total value of the selected items so far.
+    fn explore(&mut self, index: usize, remaining: &mut [u64], current_value: u64) {
+        if current_value > self.best_value {
+            self.best_value = current_value;
+            self.best_path.clone_from(&self.path);
+        }
+
+        if index == self.order.len() {
+            return;
+        }
+
+        // Prune the subtree if it can't contain a better solution. The bound is slightly
+        // inflated so that floating point rounding never cuts off an optimal solution.
+        let bound = self.surrogate_bound(index, remaining);
+        if (current_value as f64 + bound) * (1.0 + 1e-9) < (self.best_value + 1) as f64 {
+            return;
+        }
+
+        // Case 1: Take the item at the current index (if it fits in the knapsack)
+        let i = self.order[index];
+        let item = self.knapsack.get_item(i);
+        if item.get_weights().iter().zip(remaining.iter()).all(|(w, r)| w <= r) {
+            remaining.iter_mut().zip(item.get_weights()).for_each(|(r, w)| *r -= w);
+            self.path.push(i);
+            self.explore(index + 1, remaining, current_value + item.get_value());
+            self.path.pop();
+            remaining.iter_mut().zip(item.get_weights()).for_each(|(r, w)| *r += w);
+        }
+
+        // Case 2: Don't take the item at the current index
+        self.explore(index + 1, remaining, current_value);
+    }
+
+    /// Computes the fractional bound of the surrogate knapsack for the items starting at `index`.
+    ///
+    /// Items that don't fit in the remaining capacities on their own are skipped.
+    fn surrogate_bound(&self, index: usize, remaining: &[u64]) -> f64 {
+        let mut capacity: f64 = remaining
+            .iter()
+            .zip(self.knapsack.get_capacities())
+            .filter(|(_, &c)| c > 0)
+            .map(|(&r, &c)| r as f64 / c as f64)
+            .sum();
+        let mut bound = 0.0;
+
+        for (&i, &aggregated) in self.order[index..].iter().zip(&self.aggregated[index..]) {
+            let item = self.knapsack.get_item(i);
+            if item.get_weights().iter().zip(remaining).any(|(w, r)| w > r) {
+                continue;
+            }
+            let value = item.get_value() as f64;
+            if aggregated <= capacity {
+                capacity -= aggregated;
+                bound += value;
+            } else {
+                bound += value * capacity / aggregated;
+                break;
+            }
+        }
+
+        bound
+    }
+}
@@ -0,0 +1,107 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solution::MultiDimensionalKnapsackSolution;
+use crate::models::multi_dimensional_knapsack_solver::MultiDimensionalKnapsackSolver;
+use crate::models::solver_capabilities::check_decisions_size;
+
+/// Dynamic Programming implementation of the multi-dimensional Knapsack solver.
+///
+/// The table has a cell for every combination of remaining capacities, stored as one flat vector
+/// in mixed radix. It is only practical for a small number of dimensions with small capacities.
+/// Time complexity: O(n * d * Π (c_k + 1))
+/// Space complexity: O(Π (c_k + 1)), or O(n * Π (c_k + 1)) bits when the selected items are reconstructed.
+/// The decision bitsets may take at most `MAX_TABLE_CELLS` words.
+pub struct MultiDimensionalDynamicKnapsackSolver;
+
+impl MultiDimensionalDynamicKnapsackSolver {
+    /// The maximum number of cells in the table.
+    const MAX_STATES: u64 = 1 << 28;
+}
+
+impl MultiDimensionalKnapsackSolver for MultiDimensionalDynamicKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Multi-dimensional dynamic".to_string()
+    }
+
+    fn solve(&self, knapsack: &MultiDimensionalKnapsack) -> Result<u64, KnapsackError> {
+        self.table_dynamic(knapsack, false).map(|(value, _)| value)
+    }
+
+    fn solve_with_items(&self, knapsack: &MultiDimensionalKnapsack) -> Result<MultiDimensionalKnapsackSolution, KnapsackError> {
+        self.table_dynamic(knapsack, true)
+            .map(|(_, items)| MultiDimensionalKnapsackSolution::new(knapsack, items))
+    }
+}
+
+impl MultiDimensionalDynamicKnapsackSolver {
+    /// Runs the dynamic programming over all combinations of capacities.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the MultiDimensionalKnapsack instance.
+    /// * `track_items` - Whether to keep the decisions needed to restore the selected items.
+    ///
+    /// # Returns
+    /// * The maximum value and the selected items (empty if they are not tracked).
+    fn table_dynamic(&self, knapsack: &MultiDimensionalKnapsack, track_items: bool) -> Result<(u64, Vec<usize>), KnapsackError> {
+        knapsack.validate()?;
+        knapsack.check_overflow()?;
+
+        let capacities = knapsack.get_capacities();
+        // strides[k] is the distance between cells that differ by one in dimension k
+        let mut strides = Vec::with_capacity(capacities.len());
+        let mut states: u64 = 1;
+        for &capacity in capacities {
+            strides.push(states as usize);
+            states = capacity
+                .checked_add(1)
+                .and_then(|size| states.checked_mul(size))
+                .filter(|&states| states <= Self::MAX_STATES)
+                .ok_or(KnapsackError::CapacityTooLarge)?;
+        }
+        let states = states as usize;
+
+        let mut best = vec![0u64; states];
+        // taken[i] is a bitset over cells: whether item i is taken in the best solution for the cell
+        let words = if track_items { states / 64 + 1 } else { 0 };
+        check_decisions_size(knapsack.get_items_len(), words)?;
+        let mut taken = vec![vec![0u64; words]; knapsack.get_items_len()];
+
+        for (i, row) in taken.iter_mut().enumerate() {
+            let item = knapsack.get_item(i);
+            if item.get_weights().iter().zip(capacities).any(|(w, c)| w > c) {
+                continue;
+            }
+            let offset: usize = item.get_weights().iter().zip(&strides).map(|(&w, &s)| w as usize * s).sum();
+
+            // Cells are visited backwards, so every source cell still holds the previous row
+            for cell in (offset..states).rev() {
+                let fits = item.get_weights().iter().zip(capacities).zip(&strides).all(|((&w, &c), &s)| {
+                    (cell / s) % (c as usize + 1) >= w as usize
+                });
+                if fits && best[cell - offset] + item.get_value() > best[cell] {
+                    best[cell] = best[cell - offset] + item.get_value();
+                    if track_items {
+                        row[cell / 64] |= 1 << (cell % 64);
+                    }
+                }
+            }
+        }
+
+        let mut cell = states - 1;
+        let value = best[cell];
+        if !track_items {
+            return Ok((value, Vec::new()));
+        }
+
+        // Walk the decisions back from the last item
+        let mut items = Vec::new();
+        for i in (0..knapsack.get_items_len()).rev() {
+            if taken[i][cell / 64] & (1 << (cell % 64)) != 0 {
+                items.push(i);
+                cell -= knapsack.get_item(i).get_weights().iter().zip(&strides).map(|(&w, &s)| w as usize * s).sum::<usize>();
+            }
+        }
+
+        Ok((value, items))
+    }
+}
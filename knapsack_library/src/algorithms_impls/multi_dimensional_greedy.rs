@@ -0,0 +1,92 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solution::MultiDimensionalKnapsackSolution;
+use crate::models::multi_dimensional_knapsack_solver::MultiDimensionalKnapsackSolver;
+
+/// Greedy heuristic for the multi-dimensional Knapsack problem.
+///
+/// Every item gets an aggregated weight `Σ_k w_k / c_k`, i.e. the sum of the shares of the capacities
+/// it uses, and the items are added in decreasing order of value per aggregated weight while they fit
+/// in every dimension.
+/// Time complexity: O(n * (d + log n))
+/// Space complexity: O(n)
+pub struct MultiDimensionalGreedyKnapsackSolver;
+
+impl MultiDimensionalKnapsackSolver for MultiDimensionalGreedyKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Multi-dimensional greedy".to_string()
+    }
+
+    fn solve(&self, knapsack: &MultiDimensionalKnapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &MultiDimensionalKnapsack) -> Result<MultiDimensionalKnapsackSolution, KnapsackError> {
+        knapsack.validate()?;
+        knapsack.check_overflow()?;
+
+        let mut remaining = knapsack.get_capacities().to_vec();
+        let mut selected = Vec::new();
+
+        for i in sort_by_efficiency(knapsack) {
+            let weights = knapsack.get_item(i).get_weights();
+            if weights.iter().zip(remaining.iter()).all(|(w, r)| w <= r) {
+                remaining.iter_mut().zip(weights).for_each(|(r, w)| *r -= w);
+                selected.push(i);
+            }
+        }
+
+        Ok(MultiDimensionalKnapsackSolution::new(knapsack, selected))
+    }
+}
+
+/// Computes the aggregated weight `Σ_k w_k / c_k` of an item.
+///
+/// Unlike the one-dimensional greedy, the ratios aren't compared exactly: the exact sum needs the common
+/// denominator `Π_k c_k` of up to `64 * d` bits. The weight is computed in `f64` instead; it only orders
+/// the items of a heuristic, and the branch and bound inflates its surrogate bound to absorb the rounding.
+///
+/// # Arguments
+/// * `knapsack` - Reference to the MultiDimensionalKnapsack instance.
+/// * `index` - The index of the item.
+///
+/// # Returns
+/// * The aggregated weight, or `None` if the item doesn't fit in the empty knapsack.
+pub(crate) fn aggregated_weight(knapsack: &MultiDimensionalKnapsack, index: usize) -> Option<f64> {
+    let mut aggregated = 0.0;
+    for (&weight, &capacity) in knapsack.get_item(index).get_weights().iter().zip(knapsack.get_capacities()) {
+        if weight > capacity {
+            return None;
+        }
+        if weight > 0 {
+            aggregated += weight as f64 / capacity as f64;
+        }
+    }
+    Some(aggregated)
+}
+
+/// Sorts the items that fit in the empty knapsack by decreasing value per aggregated weight.
+///
+/// Items without any weight come first, since they can always be taken.
+///
+/// # Arguments
+/// * `knapsack` - Reference to the MultiDimensionalKnapsack instance.
+///
+/// # Returns
+/// * Indices of the fitting items in decreasing order of efficiency.
+pub(crate) fn sort_by_efficiency(knapsack: &MultiDimensionalKnapsack) -> Vec<usize> {
+    let mut efficiencies: Vec<(usize, f64)> = (0..knapsack.get_items_len())
+        .filter_map(|i| {
+            let aggregated = aggregated_weight(knapsack, i)?;
+            let efficiency = if aggregated > 0.0 {
+                knapsack.get_item(i).get_value() as f64 / aggregated
+            } else {
+                f64::INFINITY
+            };
+            Some((i, efficiency))
+        })
+        .collect();
+
+    efficiencies.sort_by(|a, b| b.1.total_cmp(&a.1));
+    efficiencies.into_iter().map(|(i, _)| i).collect()
+}
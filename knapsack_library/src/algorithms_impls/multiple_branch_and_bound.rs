@@ -0,0 +1,143 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::multiple_sequential::MultipleSequentialKnapsackSolver;
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solution::MultipleKnapsackSolution;
+use crate::models::multiple_knapsack_solver::MultipleKnapsackSolver;
+
+/// Branch and bound implementation of the multiple knapsack solver, in the spirit of Martello and Toth.
+///
+/// Items are sorted by their value-to-weight ratio and assigned depth-first to every knapsack
+/// they fit in, before being skipped. Subtrees are pruned with the surrogate relaxation: all the
+/// remaining capacities are merged into a single knapsack, which is solved exactly by
+/// `BranchAndBoundKnapsackSolver`. Knapsacks with equal remaining capacities are interchangeable,
+/// so an item is tried in only one of them. The incumbent is seeded with the sequential heuristic.
+/// Time complexity: exponential in the worst case, but fast on most instances.
+/// Space complexity: O(n + m)
+pub struct MultipleBranchAndBoundKnapsackSolver;
+
+impl MultipleKnapsackSolver for MultipleBranchAndBoundKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Multiple branch and bound".to_string()
+    }
+
+    fn solve(&self, knapsack: &MultipleKnapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &MultipleKnapsack) -> Result<MultipleKnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        let capacities = knapsack.get_capacities();
+        let max_capacity = capacities.iter().copied().max().unwrap_or(0);
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+
+        // Items without value or heavier than every knapsack never improve a solution
+        let mut indices: Vec<usize> = (0..knapsack.get_items_len())
+            .filter(|&i| pair(i).1 > 0 && pair(i).0 <= max_capacity)
+            .collect();
+        // Sort by value-to-weight ratio (descending order), compared exactly by cross-multiplication
+        indices.sort_by(|&a, &b| compare_ratios(pair(b), pair(a)));
+        let items: Vec<(u64, u64)> = indices.iter().map(|&i| pair(i)).collect();
+
+        let heuristic = MultipleSequentialKnapsackSolver::new(Box::new(BranchAndBoundKnapsackSolver))
+            .solve_with_items(knapsack)?;
+
+        let mut search = Search {
+            items: &items,
+            remaining: capacities.to_vec(),
+            assignments: vec![None; items.len()],
+            best_assignments: None,
+            best_value: heuristic.get_total_value(),
+        };
+        search.explore(0, 0)?;
+
+        // Keep the heuristic solution unless the search found a better one
+        let Some(best_assignments) = search.best_assignments else {
+            return Ok(heuristic);
+        };
+        let mut assignments = vec![None; knapsack.get_items_len()];
+        for (k, &i) in indices.iter().enumerate() {
+            assignments[i] = best_assignments[k];
+        }
+
+        Ok(MultipleKnapsackSolution::new(knapsack, assignments))
+    }
+}
+
+/// State of the depth-first search over items sorted by value-to-weight ratio.
+struct Search<'a> {
+    items: &'a [(u64, u64)],                          // Items sorted by decreasing value-to-weight ratio
+    remaining: Vec<u64>,                              // The remaining capacity of every knapsack
+    assignments: Vec<Option<usize>>,                  // The knapsack of every item on the current branch
+    best_assignments: Option<Vec<Option<usize>>>,     // The assignments of the best solution found by the search
+    best_value: u64,                                  // The value of the best solution found
+}
+
+impl Search<'_> {
+    /// Computes the surrogate upper bound for the items starting from `index`.
+    ///
+    /// The remaining capacities are merged into one knapsack, and the items that fit in none
+    /// of the knapsacks are dropped.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the first item that is not assigned yet.
+    fn upper_bound(&self, index: usize) -> Result<u64, KnapsackError> {
+        let max_remaining = self.remaining.iter().copied().max().unwrap_or(0);
+        let total_remaining = self.remaining.iter().fold(0u64, |total, &r| total.saturating_add(r));
+        let items: Vec<Item> = self.items[index..]
+            .iter()
+            .filter(|&&(w, _)| w <= max_remaining)
+            .map(|&(w, v)| Item::new(w, v))
+            .collect();
+
+        if items.is_empty() {
+            return Ok(0);
+        }
+        BranchAndBoundKnapsackSolver.solve(&Knapsack::new(total_remaining, items))
+    }
+
+    /// Depth-first exploration of the assignments of the items.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the current item being considered.
+    /// * `current_value` - The total value of the assigned items so far.
+    fn explore(&mut self, index: usize, current_value: u64) -> Result<(), KnapsackError> {
+        if current_value > self.best_value {
+            self.best_value = current_value;
+            self.best_assignments = Some(self.assignments.clone());
+        }
+
+        if index == self.items.len() {
+            return Ok(());
+        }
+
+        // Prune the subtree if it can't contain a better solution
+        if current_value + self.upper_bound(index)? <= self.best_value {
+            return Ok(());
+        }
+
+        // Case 1: Put the item in every knapsack it fits in, skipping knapsacks equal to the tried ones
+        let (weight, value) = self.items[index];
+        let mut tried: Vec<u64> = Vec::new();
+        for k in 0..self.remaining.len() {
+            let remaining = self.remaining[k];
+            if weight > remaining || tried.contains(&remaining) {
+                continue;
+            }
+            tried.push(remaining);
+
+            self.remaining[k] -= weight;
+            self.assignments[index] = Some(k);
+            self.explore(index + 1, current_value + value)?;
+            self.assignments[index] = None;
+            self.remaining[k] += weight;
+        }
+
+        // Case 2: Don't take the item at the current index
+        self.explore(index + 1, current_value)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solution::MultipleKnapsackSolution;
+use crate::models::multiple_knapsack_solver::MultipleKnapsackSolver;
+
+/// Sequential heuristic for the multiple knapsack problem.
+///
+/// The knapsacks are filled one after another, from the smallest capacity to the largest,
+/// by solving a single 0/1 knapsack over the items that are still unassigned. Any single knapsack
+/// solver can be used for the subproblems; with an exact one every knapsack is filled optimally,
+/// but the combined assignment may still be worse than the optimum.
+/// Time complexity: m times the complexity of the single knapsack solver
+/// Space complexity: O(n) plus the memory of the single knapsack solver
+pub struct MultipleSequentialKnapsackSolver {
+    solver: Box<dyn KnapsackSolver>, // The solver used to fill every single knapsack
+}
+
+impl MultipleSequentialKnapsackSolver {
+    /// Creates a new `MultipleSequentialKnapsackSolver` filling the knapsacks with the given solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `solver` - The single knapsack solver used for every knapsack.
+    pub fn new(solver: Box<dyn KnapsackSolver>) -> Self {
+        Self { solver }
+    }
+}
+
+impl MultipleKnapsackSolver for MultipleSequentialKnapsackSolver {
+    fn get_name(&self) -> String {
+        format!("Multiple sequential ({})", self.solver.get_name())
+    }
+
+    fn solve(&self, knapsack: &MultipleKnapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &MultipleKnapsack) -> Result<MultipleKnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        let capacities = knapsack.get_capacities();
+        let mut order: Vec<usize> = (0..capacities.len()).collect();
+        order.sort_by_key(|&k| capacities[k]);
+
+        let mut assignments = vec![None; knapsack.get_items_len()];
+        for k in order {
+            // Indices of the unassigned items in the original knapsack
+            let left: Vec<usize> = (0..knapsack.get_items_len()).filter(|&i| assignments[i].is_none()).collect();
+            if left.is_empty() {
+                break;
+            }
+
+            let items = left
+                .iter()
+                .map(|&i| Item::new(knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value()))
+                .collect();
+            let solution = self.solver.solve_with_items(&Knapsack::new(capacities[k], items))?;
+            for &i in solution.get_items() {
+                assignments[left[i]] = Some(k);
+            }
+        }
+
+        Ok(MultipleKnapsackSolution::new(knapsack, assignments))
+    }
+}
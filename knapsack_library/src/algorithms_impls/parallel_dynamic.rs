@@ -0,0 +1,199 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::{name_to_id, KnapsackSolver};
+use crate::models::solver_capabilities::{check_decisions_size, Exactness, SolverCapabilities, MAX_TABLE_CELLS};
+use std::thread;
+
+/// Multithreaded Dynamic Programming implementation of the Knapsack solver
+///
+/// Every row of the table depends only on the previous row, so the cells of a row are split
+/// into contiguous chunks which are filled by separate threads. The cells are computed exactly
+/// as in `DynamicKnapsackSolver`, so the results are identical to the sequential version.
+/// Time complexity: O(nW / t) where t is the number of threads
+/// Space complexity: O(W), or O(nW) bits when the selected items are reconstructed
+pub struct ParallelDynamicKnapsackSolver {
+    threads: usize, // The number of threads filling every row, must be positive
+}
+
+impl ParallelDynamicKnapsackSolver {
+    /// The smallest number of cells given to a thread, so that small rows don't pay for spawning threads.
+    pub const MIN_CHUNK_LEN: usize = 1 << 14;
+
+    /// Creates a new `ParallelDynamicKnapsackSolver` with the given number of threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `threads` - The number of threads filling every row of the table.
+    ///
+    /// # Returns
+    ///
+    /// A new `ParallelDynamicKnapsackSolver` instance with the specified number of threads.
+    pub fn new(threads: usize) -> Self {
+        Self { threads }
+    }
+
+    /// Gets the number of threads of the solver.
+    ///
+    /// # Returns
+    ///
+    /// The number of threads as a `usize`.
+    pub fn get_threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Runs the dynamic programming over capacities, filling every row in parallel.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    /// * `track_items` - Whether to keep the decisions needed to restore the selected items.
+    ///
+    /// # Returns
+    /// * The maximum value and the selected items (empty if they are not tracked).
+    fn parallel_dynamic(&self, knapsack: &Knapsack, track_items: bool) -> Result<(u64, Vec<usize>), KnapsackError> {
+        knapsack.check_overflow()?;
+        if self.threads == 0 {
+            return Err(KnapsackError::InvalidParameter("the number of threads must be positive".to_string()));
+        }
+
+        let n = knapsack.get_items_len();
+        let capacity = knapsack.get_capacity();
+
+        if capacity >= usize::MAX as u64 {
+            return Err(KnapsackError::CapacityTooLarge);
+        }
+
+        let capacity = capacity as usize;
+        let words = if track_items { capacity / 64 + 1 } else { 0 };
+        check_decisions_size(n, words)?;
+        // Chunks hold whole bitset words, so that the threads never share a word of the decisions
+        let chunk_len = (capacity + 1)
+            .div_ceil(self.threads)
+            .max(Self::MIN_CHUNK_LEN)
+            .next_multiple_of(64);
+        let chunks = (capacity + 1).div_ceil(chunk_len);
+
+        let mut prev = vec![0; capacity + 1];
+        let mut curr = vec![0; capacity + 1];
+        // taken[i] is a bitset over capacities: whether item i is taken in the best solution for w
+        let mut taken = vec![vec![0u64; words]; n];
+
+        for (i, row) in taken.iter_mut().enumerate() {
+            let item = knapsack.get_item(i);
+            let weight = item.get_weight() as usize;
+            let value = item.get_value();
+
+            // Without tracking, every chunk gets an empty slice of decisions
+            let mut row_chunks: Vec<&mut [u64]> = row.chunks_mut(chunk_len / 64).collect();
+            row_chunks.resize_with(chunks, Default::default);
+            let previous_row = &prev[..];
+            let mut jobs = curr.chunks_mut(chunk_len).zip(row_chunks).enumerate();
+
+            thread::scope(|scope| {
+                // The first chunk is filled by the current thread, so small rows don't spawn any threads
+                let first = jobs.next();
+                for (k, (cells, decisions)) in jobs {
+                    scope.spawn(move || fill_chunk(previous_row, cells, decisions, k * chunk_len, weight, value));
+                }
+                if let Some((_, (cells, decisions))) = first {
+                    fill_chunk(previous_row, cells, decisions, 0, weight, value);
+                }
+            });
+            std::mem::swap(&mut prev, &mut curr);
+        }
+
+        // Walk the decisions back from the last item
+        let mut items = Vec::new();
+        if track_items {
+            let mut w = capacity;
+            for i in (0..n).rev() {
+                if taken[i][w / 64] & (1 << (w % 64)) != 0 {
+                    items.push(i);
+                    w -= knapsack.get_item(i).get_weight() as usize;
+                }
+            }
+        }
+
+        Ok((prev[capacity], items))
+    }
+}
+
+impl KnapsackSolver for ParallelDynamicKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name with its number of threads, e.g. "Parallel dynamic 4".
+    fn get_name(&self) -> String {
+        format!("Parallel dynamic {}", self.threads)
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "parallel_dynamic_<threads>", e.g. "parallel_dynamic_4".
+    fn get_id(&self) -> String {
+        name_to_id(&self.get_name())
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(nW / t)", "O(W + nW / 64)").with_max_capacity(MAX_TABLE_CELLS - 1)
+    }
+
+    /// Solves the knapsack problem using the multithreaded dynamic programming.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value that can be achieved.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.parallel_dynamic(knapsack, false).map(|(value, _)| value)
+    }
+
+    /// Solves the knapsack problem using the multithreaded dynamic programming and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the same items as `DynamicKnapsackSolver` selects.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.parallel_dynamic(knapsack, true)
+            .map(|(_, items)| KnapsackSolution::new(knapsack, items))
+    }
+}
+
+/// Fills the cells `start..start + cells.len()` of the current row from the previous row.
+///
+/// # Arguments
+/// * `prev` - The whole previous row.
+/// * `cells` - The chunk of the current row, starting at a multiple of 64.
+/// * `decisions` - The words of the decisions bitset for the chunk, or an empty slice if they are not tracked.
+/// * `start` - The capacity of the first cell of the chunk.
+/// * `weight` - The weight of the current item.
+/// * `value` - The value of the current item.
+fn fill_chunk(prev: &[u64], cells: &mut [u64], decisions: &mut [u64], start: usize, weight: usize, value: u64) {
+    let track_items = !decisions.is_empty();
+    for (offset, cell) in cells.iter_mut().enumerate() {
+        let w = start + offset;
+        *cell = if weight <= w && prev[w - weight] + value > prev[w] {
+            if track_items {
+                decisions[offset / 64] |= 1 << (offset % 64);
+            }
+            prev[w - weight] + value
+        } else {
+            prev[w]
+        };
+    }
+}
@@ -0,0 +1,74 @@
+use crate::algorithms_impls::preprocessing::Preprocessor;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::SolveContext;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::SolverCapabilities;
+
+/// A solver which reduces the knapsack with a `Preprocessor` before solving it with another solver.
+///
+/// Any solver can opt into preprocessing by being wrapped in this one. The selected items
+/// of the reduced knapsack are mapped back to the original knapsack.
+pub struct PreprocessedKnapsackSolver {
+    solver: Box<dyn KnapsackSolver>, // The solver of the reduced knapsack
+    preprocessor: Preprocessor,      // The reductions applied before solving
+}
+
+impl PreprocessedKnapsackSolver {
+    /// Creates a new `PreprocessedKnapsackSolver` applying all reductions.
+    ///
+    /// # Arguments
+    ///
+    /// * `solver` - The solver of the reduced knapsack.
+    pub fn new(solver: Box<dyn KnapsackSolver>) -> Self {
+        Self::with_preprocessor(solver, Preprocessor::new())
+    }
+
+    /// Creates a new `PreprocessedKnapsackSolver` applying the reductions of the given preprocessor.
+    ///
+    /// # Arguments
+    ///
+    /// * `solver` - The solver of the reduced knapsack.
+    /// * `preprocessor` - The reductions applied before solving.
+    pub fn with_preprocessor(solver: Box<dyn KnapsackSolver>, preprocessor: Preprocessor) -> Self {
+        Self { solver, preprocessor }
+    }
+}
+
+impl KnapsackSolver for PreprocessedKnapsackSolver {
+    fn get_name(&self) -> String {
+        format!("Preprocessed ({})", self.solver.get_name())
+    }
+
+    fn get_id(&self) -> String {
+        format!("preprocessed_{}", self.solver.get_id())
+    }
+
+    // The reduced knapsack is never larger than the original one
+    fn get_capabilities(&self) -> SolverCapabilities {
+        self.solver.get_capabilities()
+    }
+
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        knapsack.check_overflow()?;
+        let reduced = self.preprocessor.reduce(knapsack);
+        let value = self.solver.solve(reduced.get_knapsack())?;
+        Ok(value + reduced.get_fixed_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        knapsack.check_overflow()?;
+        let reduced = self.preprocessor.reduce(knapsack);
+        let solution = self.solver.solve_with_items(reduced.get_knapsack())?;
+        Ok(reduced.restore(knapsack, &solution))
+    }
+
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        knapsack.check_overflow()?;
+        let reduced = self.preprocessor.reduce(knapsack);
+        let outcome = self.solver.solve_with_context(reduced.get_knapsack(), context)?;
+        Ok(SolveOutcome::new(reduced.restore(knapsack, outcome.get_solution()), outcome.is_proven()))
+    }
+}
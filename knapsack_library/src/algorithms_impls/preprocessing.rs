@@ -0,0 +1,239 @@
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::knapsack::Knapsack;
+use crate::models::reduced_knapsack::ReducedKnapsack;
+
+/// Reduces a 0/1 knapsack instance before solving it.
+///
+/// The following reductions are applied, every one of them keeps at least one optimal solution:
+/// * items heavier than the capacity and items without value are removed, items without weight are always taken;
+/// * dominance: item `j` is removed if the items dominating it (not heavier and not less valuable)
+///   can't be taken together with it, since then `j` can be swapped for one of them in any solution;
+/// * variable fixing (Ingargiola–Korsh): if the Dantzig bound with item `j` taken (skipped) is below
+///   the greedy value, no optimal solution takes (skips) `j`, so it is removed (always taken);
+/// * the common divisor of the weights is divided out of the weights and the capacity.
+///
+/// Dominance and variable fixing assume that every item can be taken once, so for bounded knapsacks
+/// (items with several copies) only the removal of useless items and the division of weights are applied.
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub struct Preprocessor {
+    dominance: bool,       // Whether dominated items are removed
+    variable_fixing: bool, // Whether items are fixed by the bounds
+    weight_gcd: bool,      // Whether the common divisor of the weights is divided out
+}
+
+impl Preprocessor {
+    /// Creates a preprocessor with all reductions enabled.
+    ///
+    /// # Returns
+    ///
+    /// A new `Preprocessor` instance.
+    pub fn new() -> Self {
+        Self {
+            dominance: true,
+            variable_fixing: true,
+            weight_gcd: true,
+        }
+    }
+
+    /// Enables or disables the removal of dominated items.
+    pub fn with_dominance(mut self, enabled: bool) -> Self {
+        self.dominance = enabled;
+        self
+    }
+
+    /// Enables or disables the fixing of items by the bounds.
+    pub fn with_variable_fixing(mut self, enabled: bool) -> Self {
+        self.variable_fixing = enabled;
+        self
+    }
+
+    /// Enables or disables the division of the weights by their common divisor.
+    pub fn with_weight_gcd(mut self, enabled: bool) -> Self {
+        self.weight_gcd = enabled;
+        self
+    }
+
+    /// Reduces the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` to reduce.
+    ///
+    /// # Returns
+    ///
+    /// The `ReducedKnapsack` with the mapping of its items back to the original knapsack.
+    pub fn reduce(&self, knapsack: &Knapsack) -> ReducedKnapsack {
+        let mut capacity = knapsack.get_capacity();
+        let bounded = (0..knapsack.get_items_len()).any(|i| knapsack.get_item(i).get_count() > 1);
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+
+        let mut fixed_items = Vec::new();
+        let mut items = Vec::new();
+        for i in 0..knapsack.get_items_len() {
+            let (weight, value) = pair(i);
+            if weight > capacity || value == 0 {
+                continue;
+            }
+            if weight == 0 && !bounded {
+                fixed_items.push(i);
+                continue;
+            }
+            items.push(i);
+        }
+
+        if self.dominance && !bounded {
+            items = remove_dominated(&items, capacity, pair);
+        }
+
+        if self.variable_fixing && !bounded {
+            let (fixed_in, fixed_out) = fix_variables(&items, capacity, pair);
+            capacity -= fixed_in.iter().map(|&i| pair(i).0).sum::<u64>();
+            items.retain(|i| fixed_in.binary_search(i).is_err() && fixed_out.binary_search(i).is_err());
+            fixed_items.extend(fixed_in);
+            // Taken items leave less room for the others
+            items.retain(|&i| pair(i).0 <= capacity);
+        }
+
+        let mut divisor = 1;
+        if self.weight_gcd {
+            divisor = items.iter().fold(0, |g, &i| gcd(g, pair(i).0)).max(1);
+        }
+
+        let reduced_items = items
+            .iter()
+            .map(|&i| {
+                let item = knapsack.get_item(i);
+                item.with_weight(item.get_weight() / divisor)
+            })
+            .collect();
+        fixed_items.sort_unstable();
+        let fixed_value = fixed_items.iter().map(|&i| pair(i).1).sum();
+
+        ReducedKnapsack::new(
+            Knapsack::new(capacity / divisor, reduced_items),
+            items,
+            fixed_items,
+            fixed_value,
+            divisor,
+        )
+    }
+}
+
+impl Default for Preprocessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Removes the items which can be swapped for the items dominating them in any solution.
+///
+/// The items are visited from the lightest, the most valuable first, so that the dominating items
+/// of an item are visited before it. The total weight of the kept dominating items is looked up
+/// in a Fenwick tree indexed by the rank of the value.
+fn remove_dominated(items: &[usize], capacity: u64, pair: impl Fn(usize) -> (u64, u64)) -> Vec<usize> {
+    let mut order = items.to_vec();
+    order.sort_by(|&a, &b| pair(a).0.cmp(&pair(b).0).then(pair(b).1.cmp(&pair(a).1)).then(a.cmp(&b)));
+
+    // Distinct values in descending order, so that a prefix of ranks holds the values at least as large
+    let mut values: Vec<u64> = items.iter().map(|&i| pair(i).1).collect();
+    values.sort_unstable_by(|a, b| b.cmp(a));
+    values.dedup();
+
+    let mut tree = vec![0u128; values.len() + 1];
+    let mut kept = Vec::new();
+    for i in order {
+        let (weight, value) = pair(i);
+        let rank = values.partition_point(|&v| v > value) + 1;
+
+        let mut dominating_weight = 0;
+        let mut r = rank;
+        while r > 0 {
+            dominating_weight += tree[r];
+            r &= r - 1;
+        }
+        if dominating_weight + weight as u128 > capacity as u128 {
+            continue;
+        }
+
+        let mut r = rank;
+        while r < tree.len() {
+            tree[r] += weight as u128;
+            r += r & r.wrapping_neg();
+        }
+        kept.push(i);
+    }
+
+    kept.sort_unstable();
+    kept
+}
+
+/// Finds the items which are taken (skipped) by every optimal solution.
+///
+/// # Returns
+///
+/// The items to take and the items to skip, in increasing order.
+fn fix_variables(items: &[usize], capacity: u64, pair: impl Fn(usize) -> (u64, u64)) -> (Vec<usize>, Vec<usize>) {
+    let mut order = items.to_vec();
+    order.sort_by(|&a, &b| compare_ratios(pair(b), pair(a)).then(a.cmp(&b)));
+
+    let mut prefix_weights = vec![0u128; order.len() + 1];
+    let mut prefix_values = vec![0u128; order.len() + 1];
+    for (k, &i) in order.iter().enumerate() {
+        prefix_weights[k + 1] = prefix_weights[k] + pair(i).0 as u128;
+        prefix_values[k + 1] = prefix_values[k] + pair(i).1 as u128;
+    }
+
+    // The Dantzig bound over the items in `order` without the one at position `skip`
+    let dantzig = |capacity: u128, skip: usize| -> u128 {
+        let skipped = |k: usize, prefix: &[u128], amount: u64| prefix[k] - if k > skip { amount as u128 } else { 0 };
+        let (skip_weight, skip_value) = pair(order[skip]);
+        // The longest prefix fitting in the capacity, the weights of the prefixes never decrease
+        let (mut k, mut high) = (0, order.len());
+        while k < high {
+            let middle = (k + high).div_ceil(2);
+            if skipped(middle, &prefix_weights, skip_weight) <= capacity {
+                k = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        let mut bound = skipped(k, &prefix_values, skip_value);
+        // The next item is never the skipped one, since skipping it doesn't change the weight of the prefix
+        if k < order.len() {
+            let (weight, value) = pair(order[k]);
+            bound += (capacity - skipped(k, &prefix_weights, skip_weight)) * value as u128 / weight as u128;
+        }
+        bound
+    };
+
+    // The greedy value is a lower bound of the optimum
+    let mut lower_bound = 0u128;
+    let mut left = capacity;
+    for &i in &order {
+        let (weight, value) = pair(i);
+        if weight <= left {
+            left -= weight;
+            lower_bound += value as u128;
+        }
+    }
+
+    let mut fixed_in = Vec::new();
+    let mut fixed_out = Vec::new();
+    for (p, &i) in order.iter().enumerate() {
+        let (weight, value) = pair(i);
+        if value as u128 + dantzig((capacity - weight) as u128, p) < lower_bound {
+            fixed_out.push(i);
+        } else if dantzig(capacity as u128, p) < lower_bound {
+            fixed_in.push(i);
+        }
+    }
+    fixed_in.sort_unstable();
+    fixed_out.sort_unstable();
+    (fixed_in, fixed_out)
+}
+
+/// Computes the greatest common divisor.
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
@@ -0,0 +1,175 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solver_capabilities::{check_decisions_size, Exactness, SolverCapabilities, MAX_TABLE_CELLS};
+
+/// Dynamic Programming implementation of the Knapsack solver indexed by value.
+///
+/// Instead of the best value for every capacity, this solver keeps the minimum weight needed
+/// to reach every total value from `0` to `P`, the sum of the values of the items. The answer is
+/// the largest value whose minimum weight fits in the knapsack. The capacity doesn't affect the
+/// running time, so this solver suits knapsacks with huge weights and small values.
+/// Time complexity: O(nP)
+/// Space complexity: O(P), or O(nP) bits when the selected items are reconstructed
+pub struct ProfitDynamicKnapsackSolver;
+
+impl KnapsackSolver for ProfitDynamicKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Profit dynamic".
+    fn get_name(&self) -> String {
+        "Profit dynamic".to_string()
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "profit_dynamic".
+    fn get_id(&self) -> String {
+        "profit_dynamic".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(nP)", "O(P + nP / 64)").with_max_profit_sum(MAX_TABLE_CELLS as u128 - 1)
+    }
+
+    /// Solves the knapsack problem using dynamic programming over total values.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` value representing the maximum value that can be achieved.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.profit_dynamic(knapsack, false).map(|(value, _)| value)
+    }
+
+    /// Solves the knapsack problem using dynamic programming over total values and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.profit_dynamic(knapsack, true)
+            .map(|(_, items)| KnapsackSolution::new(knapsack, items))
+    }
+}
+
+impl ProfitDynamicKnapsackSolver {
+    /// Checks whether this solver is expected to be faster than `DynamicKnapsackSolver` for the knapsack.
+    ///
+    /// The table of this solver has `P + 1` cells, while the table indexed by capacity has `W + 1`,
+    /// so it is preferred when the sum of the values of the fitting items is smaller than the capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the sum of the values is smaller than the capacity.
+    pub fn is_preferred(knapsack: &Knapsack) -> bool {
+        Self::profit_sum(knapsack) < knapsack.get_capacity() as u128
+    }
+
+    /// Computes the sum of the values of the items which fit in the knapsack alone.
+    fn profit_sum(knapsack: &Knapsack) -> u128 {
+        (0..knapsack.get_items_len())
+            .map(|i| knapsack.get_item(i))
+            .filter(|item| item.get_weight() <= knapsack.get_capacity())
+            .map(|item| item.get_value() as u128)
+            .sum()
+    }
+
+    /// Runs the dynamic programming over total values.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    /// * `track_items` - Whether to keep the decisions needed to restore the selected items.
+    ///
+    /// # Returns
+    /// * The maximum value and the selected items (empty if they are not tracked).
+    fn profit_dynamic(&self, knapsack: &Knapsack, track_items: bool) -> Result<(u64, Vec<usize>), KnapsackError> {
+        knapsack.check_overflow()?;
+        let capacity = knapsack.get_capacity();
+        let profit_sum = Self::profit_sum(knapsack);
+
+        if profit_sum >= usize::MAX as u128 {
+            return Err(KnapsackError::CapacityTooLarge);
+        }
+
+        let profit_sum = profit_sum as usize;
+
+        // Items that don't fit alone can't be part of any solution
+        let items: Vec<usize> = (0..knapsack.get_items_len())
+            .filter(|&i| knapsack.get_item(i).get_weight() <= capacity)
+            .collect();
+
+        let words = if track_items { profit_sum / 64 + 1 } else { 0 };
+        check_decisions_size(items.len(), words)?;
+
+        // min_weight[p] is the minimum weight of a set of items with total value p (None if unreachable).
+        // Every stored set fits in the knapsack, even if the capacity is u64::MAX
+        let mut min_weight: Vec<Option<u64>> = vec![None; profit_sum + 1];
+        min_weight[0] = Some(0);
+        // taken[k] is a bitset over values: whether items[k] was added to the best set for p
+        let mut taken = vec![vec![0u64; words]; items.len()];
+        // The largest value reached so far bounds the inner loop
+        let mut reached = 0;
+
+        for (row, &i) in taken.iter_mut().zip(items.iter()) {
+            let weight = knapsack.get_item(i).get_weight();
+            let value = knapsack.get_item(i).get_value() as usize;
+
+            for p in (value..=reached + value).rev() {
+                let Some(previous) = min_weight[p - value] else {
+                    continue;
+                };
+                // Sets heavier than the capacity are never extended
+                if capacity - previous < weight {
+                    continue;
+                }
+                if min_weight[p].is_none_or(|current| previous + weight < current) {
+                    min_weight[p] = Some(previous + weight);
+                    if track_items {
+                        row[p / 64] |= 1 << (p % 64);
+                    }
+                }
+            }
+            reached += value;
+        }
+
+        let best = (0..=profit_sum).rev().find(|&p| min_weight[p].is_some()).unwrap_or(0);
+
+        if !track_items {
+            return Ok((best as u64, Vec::new()));
+        }
+
+        // Walk the decisions back from the last item
+        let mut selected = Vec::new();
+        let mut p = best;
+        for (k, &i) in items.iter().enumerate().rev() {
+            if taken[k][p / 64] & (1 << (p % 64)) != 0 {
+                selected.push(i);
+                p -= knapsack.get_item(i).get_value() as usize;
+            }
+        }
+
+        Ok((best as u64, selected))
+    }
+}
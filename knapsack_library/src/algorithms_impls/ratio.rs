@@ -0,0 +1,8 @@
+use std::cmp::Ordering;
+
+/// Compares value-to-weight ratios of two `(weight, value)` pairs with non-zero weights.
+///
+/// The ratios are compared exactly by cross-multiplication in `u128`, which can't overflow.
+pub(crate) fn compare_ratios(a: (u64, u64), b: (u64, u64)) -> Ordering {
+    (a.1 as u128 * b.0 as u128).cmp(&(b.1 as u128 * a.0 as u128))
+}
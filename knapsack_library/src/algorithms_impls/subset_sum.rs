@@ -0,0 +1,180 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities, MAX_TABLE_CELLS};
+
+/// A solver for subset-sum instances of the knapsack problem using a bitset dynamic programming.
+///
+/// When the values of all items are proportional to their weights (e.g. equal to them), the best
+/// solution is the reachable total weight closest to the capacity. The reachable weights are kept
+/// in a bitset and every item is added with a shift-OR over packed `u64` words, so a row costs
+/// W / 64 word operations instead of W cell updates.
+/// Time complexity: O(nW / 64)
+/// Space complexity: O(W) bits, or O(W) words when the selected items are reconstructed
+pub struct SubsetSumKnapsackSolver;
+
+impl SubsetSumKnapsackSolver {
+    /// Checks whether the knapsack is a subset-sum instance this solver can handle.
+    ///
+    /// The values of all items that fit in the knapsack must be proportional to their weights,
+    /// and items without weight must have no value (see `Knapsack::has_proportional_values`).
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the values are proportional to the weights.
+    pub fn is_subset_sum(knapsack: &Knapsack) -> bool {
+        knapsack.has_proportional_values()
+    }
+
+    /// Runs the bitset dynamic programming over reachable weights.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the Knapsack instance.
+    /// * `track_items` - Whether to keep the decisions needed to restore the selected items.
+    ///
+    /// # Returns
+    /// * The maximum value and the selected items (empty if they are not tracked).
+    fn subset_sum(&self, knapsack: &Knapsack, track_items: bool) -> Result<(u64, Vec<usize>), KnapsackError> {
+        knapsack.check_overflow()?;
+        self.get_capabilities().check(knapsack)?;
+
+        let capacity = knapsack.get_capacity() as usize;
+
+        // Items without weight have no value, and too heavy items can't be taken
+        let items: Vec<usize> = (0..knapsack.get_items_len())
+            .filter(|&i| (1..=capacity).contains(&(knapsack.get_item(i).get_weight() as usize)))
+            .collect();
+
+        if items.len() > u32::MAX as usize {
+            return Err(KnapsackError::TooManyItems { items: items.len(), max: u32::MAX as usize });
+        }
+
+        let words = capacity / 64 + 1;
+        // The bits of the last word above the capacity are never set
+        let last_mask = u64::MAX >> (words * 64 - (capacity + 1));
+        // reach[s / 64] has the bit s % 64 set if the total weight s is reachable
+        let mut reach = vec![0u64; words];
+        reach[0] = 1;
+        // first[s] is the position in `items` of the item which made the total weight s reachable
+        let mut first = vec![u32::MAX; if track_items { capacity + 1 } else { 0 }];
+
+        for (k, &i) in items.iter().enumerate() {
+            let weight = knapsack.get_item(i).get_weight() as usize;
+            let (shift_words, shift_bits) = (weight / 64, weight % 64);
+
+            // Going from the high words down, the lower words still hold the previous row
+            for j in (shift_words..words).rev() {
+                let mut shifted = reach[j - shift_words] << shift_bits;
+                if shift_bits > 0 && j > shift_words {
+                    shifted |= reach[j - shift_words - 1] >> (64 - shift_bits);
+                }
+                if j == words - 1 {
+                    shifted &= last_mask;
+                }
+
+                let mut added = shifted & !reach[j];
+                reach[j] |= added;
+                if track_items {
+                    while added != 0 {
+                        first[j * 64 + added.trailing_zeros() as usize] = k as u32;
+                        added &= added - 1;
+                    }
+                }
+            }
+        }
+
+        let best = (0..words)
+            .rev()
+            .find(|&j| reach[j] != 0)
+            .map_or(0, |j| j * 64 + 63 - reach[j].leading_zeros() as usize);
+
+        // Walk the items back: the weight before an item was reached by earlier items only
+        let mut selected = Vec::new();
+        if track_items {
+            let mut s = best;
+            while s > 0 {
+                let i = items[first[s] as usize];
+                selected.push(i);
+                s -= knapsack.get_item(i).get_weight() as usize;
+            }
+        }
+
+        // All values have the ratio of any taken item, so the value follows from the total weight
+        let value = match items.first() {
+            Some(&i) => {
+                let item = knapsack.get_item(i);
+                best as u128 * item.get_value() as u128 / item.get_weight() as u128
+            }
+            None => 0,
+        };
+        let value = u64::try_from(value).map_err(|_| KnapsackError::ArithmeticOverflow)?;
+
+        Ok((value, selected))
+    }
+}
+
+impl KnapsackSolver for SubsetSumKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm name: "Subset sum".
+    fn get_name(&self) -> String {
+        "Subset sum".to_string()
+    }
+
+    /// Returns the stable identifier of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the algorithm identifier: "subset_sum".
+    fn get_id(&self) -> String {
+        "subset_sum".to_string()
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// Only subset-sum instances are supported, see `is_subset_sum`.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::new(Exactness::Exact, "O(nW / 64)", "O(W)")
+            .with_max_capacity(MAX_TABLE_CELLS - 1)
+            .with_proportional_values()
+    }
+
+    /// Solves a subset-sum instance of the knapsack problem using the bitset dynamic programming.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value that can be achieved, or `KnapsackError::InvalidInstance`
+    /// if the values are not proportional to the weights.
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.subset_sum(knapsack, false).map(|(value, _)| value)
+    }
+
+    /// Solves a subset-sum instance of the knapsack problem and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` object containing the items and capacity.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the items of the best combination.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.subset_sum(knapsack, true)
+            .map(|(_, items)| KnapsackSolution::new(knapsack, items))
+    }
+}
@@ -0,0 +1,100 @@
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::algorithms_impls::unbounded_reduction::reduce;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::models::unbounded_knapsack_solution::UnboundedKnapsackSolution;
+use crate::models::unbounded_knapsack_solver::UnboundedKnapsackSolver;
+
+/// Branch and bound implementation of the unbounded Knapsack solver.
+///
+/// This solver sorts the item types by their value-to-weight ratio and explores the number of copies
+/// of every type depth-first, starting from the largest count that fits. A subtree is pruned when even
+/// filling the remaining capacity with the ratio of the current type can't beat the incumbent.
+/// The same reductions as for the dynamic programming are applied beforehand.
+/// Time complexity: exponential in the worst case, but fast on most instances.
+/// Space complexity: O(n)
+pub struct UnboundedBranchAndBoundKnapsackSolver;
+
+impl UnboundedKnapsackSolver for UnboundedBranchAndBoundKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Unbounded branch and bound".to_string()
+    }
+
+    fn solve(&self, knapsack: &UnboundedKnapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &UnboundedKnapsack) -> Result<UnboundedKnapsackSolution, KnapsackError> {
+        let reduced = reduce(knapsack)?;
+        let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+
+        let mut indices = reduced.items.clone();
+        indices.sort_by(|&a, &b| compare_ratios(pair(b), pair(a)));
+        let items: Vec<(u64, u64)> = indices.iter().map(|&i| pair(i)).collect();
+
+        let mut search = Search {
+            items: &items,
+            exact_fill: knapsack.is_exact_fill(),
+            counts: vec![0; items.len()],
+            best_counts: None,
+            best_value: 0,
+        };
+        search.explore(0, reduced.capacity, 0);
+
+        let best_counts = search
+            .best_counts
+            .ok_or(KnapsackError::Infeasible)?;
+
+        let mut counts = vec![0; knapsack.get_items_len()];
+        for (k, &i) in indices.iter().enumerate() {
+            counts[i] += best_counts[k];
+        }
+        if let Some(fixed) = reduced.fixed_item {
+            counts[fixed] += reduced.fixed_copies;
+        }
+
+        Ok(UnboundedKnapsackSolution::new(knapsack, counts))
+    }
+}
+
+/// State of the depth-first search over item types sorted by value-to-weight ratio.
+struct Search<'a> {
+    items: &'a [(u64, u64)],       // Item types sorted by decreasing value-to-weight ratio
+    exact_fill: bool,              // Whether only exactly filled knapsacks are solutions
+    counts: Vec<u64>,              // Copies of every item type taken on the current branch
+    best_counts: Option<Vec<u64>>, // Copies of every item type in the best solution found
+    best_value: u64,               // The value of the best solution found
+}
+
+impl Search<'_> {
+    /// Depth-first exploration of the number of copies of every item type.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the current item type being considered.
+    /// * `remaining` - The remaining capacity of the knapsack.
+    /// * `current_value` - The total value of the selected items so far.
+    fn explore(&mut self, index: usize, remaining: u64, current_value: u64) {
+        let is_solution = !self.exact_fill || remaining == 0;
+        if is_solution && (self.best_counts.is_none() || current_value > self.best_value) {
+            self.best_value = current_value;
+            self.best_counts = Some(self.counts.clone());
+        }
+
+        if index == self.items.len() || remaining == 0 {
+            return;
+        }
+
+        // The remaining item types have at most the ratio of the current one
+        let (weight, value) = self.items[index];
+        let bound = (remaining as u128 * value as u128 / weight as u128) as u64;
+        if self.best_counts.is_some() && current_value + bound <= self.best_value {
+            return;
+        }
+
+        for count in (0..=remaining / weight).rev() {
+            self.counts[index] = count;
+            self.explore(index + 1, remaining - count * weight, current_value + count * value);
+        }
+        self.counts[index] = 0;
+    }
+}
@@ -0,0 +1,94 @@
+use crate::algorithms_impls::unbounded_reduction::{reduce, ReducedUnboundedKnapsack};
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::models::unbounded_knapsack_solution::UnboundedKnapsackSolution;
+use crate::models::unbounded_knapsack_solver::UnboundedKnapsackSolver;
+
+/// Dynamic Programming implementation of the unbounded Knapsack solver.
+///
+/// This solver computes the best value for every capacity from `0` to `W` by trying every item type
+/// as the last one taken. Dominated item types are removed and, for large capacities, copies of the
+/// best-ratio item type are taken in advance, so `W` is at most `w_best * (w_max + 2)`.
+/// Time complexity: O(nW)
+/// Space complexity: O(W)
+pub struct UnboundedDynamicKnapsackSolver;
+
+impl UnboundedKnapsackSolver for UnboundedDynamicKnapsackSolver {
+    fn get_name(&self) -> String {
+        "Unbounded dynamic".to_string()
+    }
+
+    fn solve(&self, knapsack: &UnboundedKnapsack) -> Result<u64, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    fn solve_with_items(&self, knapsack: &UnboundedKnapsack) -> Result<UnboundedKnapsackSolution, KnapsackError> {
+        let reduced = reduce(knapsack)?;
+
+        if reduced.capacity >= usize::MAX as u64 {
+            return Err(KnapsackError::CapacityTooLarge);
+        }
+
+        let capacity = reduced.capacity as usize;
+
+        // best[w] is the maximum value of items with total weight at most w (exactly w for exact fill),
+        // last[w] is the item type taken last in it
+        let mut best: Vec<Option<u64>> = vec![None; capacity + 1];
+        let mut last: Vec<Option<usize>> = vec![None; capacity + 1];
+        if knapsack.is_exact_fill() {
+            best[0] = Some(0);
+        } else {
+            best.iter_mut().for_each(|value| *value = Some(0));
+        }
+
+        for w in 1..=capacity {
+            for &i in &reduced.items {
+                let item = knapsack.get_item(i);
+                let weight = item.get_weight() as usize;
+                if weight > w {
+                    continue;
+                }
+                if let Some(previous) = best[w - weight] {
+                    let candidate = previous + item.get_value();
+                    if best[w].is_none_or(|current| candidate > current) {
+                        best[w] = Some(candidate);
+                        last[w] = Some(i);
+                    }
+                }
+            }
+        }
+
+        if best[capacity].is_none() {
+            return Err(KnapsackError::Infeasible);
+        }
+
+        Ok(Self::restore(knapsack, &reduced, &last))
+    }
+}
+
+impl UnboundedDynamicKnapsackSolver {
+    /// Restores the selected copies of item types from the last taken item of every state.
+    ///
+    /// # Arguments
+    /// * `knapsack` - Reference to the UnboundedKnapsack instance.
+    /// * `reduced` - The reduced instance the dynamic programming was built for.
+    /// * `last` - The item type taken last for every capacity.
+    fn restore(
+        knapsack: &UnboundedKnapsack,
+        reduced: &ReducedUnboundedKnapsack,
+        last: &[Option<usize>],
+    ) -> UnboundedKnapsackSolution {
+        let mut counts = vec![0; knapsack.get_items_len()];
+        if let Some(fixed) = reduced.fixed_item {
+            counts[fixed] += reduced.fixed_copies;
+        }
+
+        let mut w = reduced.capacity as usize;
+        while let Some(i) = last[w] {
+            counts[i] += 1;
+            w -= knapsack.get_item(i).get_weight() as usize;
+        }
+
+        UnboundedKnapsackSolution::new(knapsack, counts)
+    }
+}
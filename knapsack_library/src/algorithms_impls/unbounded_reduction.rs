@@ -0,0 +1,103 @@
+use crate::algorithms_impls::ratio::compare_ratios;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+
+/// An unbounded knapsack instance after the reductions shared by the unbounded solvers.
+///
+/// Solving the reduced instance and adding `fixed_copies` copies of `fixed_item` gives an
+/// optimal solution of the original instance.
+pub(crate) struct ReducedUnboundedKnapsack {
+    pub items: Vec<usize>,           // Indices of the remaining item types in the original knapsack
+    pub capacity: u64,               // The capacity left for the remaining item types
+    pub fixed_item: Option<usize>,   // The item type with the best value-to-weight ratio
+    pub fixed_copies: u64,           // The number of copies of `fixed_item` taken in advance
+}
+
+/// Reduces an unbounded knapsack instance before solving it.
+///
+/// The following reductions are applied:
+/// * item types heavier than the capacity are removed, as well as useless item types without weight
+///   and value (and, unless the knapsack is filled exactly, without value);
+/// * dominated item types are removed: type `j` is dominated by type `i` if `⌊w_j / w_i⌋` copies of `i`
+///   are worth at least as much as one copy of `j`. For exact fill only types of equal weight are compared,
+///   since replacing an item by lighter ones changes the total weight;
+/// * periodicity: some optimal solution takes at most `w_b - 1` copies of item types other than
+///   the best-ratio type `b` (otherwise a subset of them has a total weight divisible by `w_b` and can be
+///   replaced by copies of `b`). So when the capacity exceeds `w_b * (w_max + 1)`, copies of `b` are taken
+///   in advance and only the remaining capacity needs to be solved.
+///
+/// # Arguments
+/// * `knapsack` - Reference to the UnboundedKnapsack instance.
+///
+/// # Returns
+/// * The reduced instance, or an error if the value of the knapsack is unbounded
+///   or the value of a solution may not fit in `u64`.
+pub(crate) fn reduce(knapsack: &UnboundedKnapsack) -> Result<ReducedUnboundedKnapsack, KnapsackError> {
+    let capacity = knapsack.get_capacity();
+    let exact_fill = knapsack.is_exact_fill();
+    let pair = |i: usize| (knapsack.get_item(i).get_weight(), knapsack.get_item(i).get_value());
+
+    let mut items: Vec<usize> = Vec::new();
+    for i in 0..knapsack.get_items_len() {
+        let (weight, value) = pair(i);
+        if weight == 0 && value > 0 {
+            return Err(KnapsackError::InvalidInstance(
+                "an item with zero weight and positive value makes the value unbounded".to_string(),
+            ));
+        }
+        if weight == 0 || weight > capacity || (value == 0 && !exact_fill) {
+            continue;
+        }
+        items.push(i);
+    }
+
+    // Sort by weight, and by decreasing value among equal weights, so that every item type
+    // can only be dominated by one of the previous types
+    items.sort_by(|&a, &b| pair(a).0.cmp(&pair(b).0).then(pair(b).1.cmp(&pair(a).1)));
+
+    let mut kept: Vec<usize> = Vec::with_capacity(items.len());
+    for i in items {
+        let (weight, value) = pair(i);
+        let dominated = kept.iter().any(|&k| {
+            let (kept_weight, kept_value) = pair(k);
+            if exact_fill {
+                kept_weight == weight && kept_value >= value
+            } else {
+                (weight / kept_weight) as u128 * kept_value as u128 >= value as u128
+            }
+        });
+        if !dominated {
+            kept.push(i);
+        }
+    }
+
+    let best = kept.iter().copied().max_by(|&a, &b| compare_ratios(pair(a), pair(b)));
+
+    // No solution is worth more than the capacity filled with the best ratio, so if this bound
+    // fits in `u64`, no sum of values overflows
+    if let Some(best) = best {
+        let (weight, value) = pair(best);
+        if capacity as u128 * value as u128 / weight as u128 > u64::MAX as u128 {
+            return Err(KnapsackError::ArithmeticOverflow);
+        }
+    }
+    let max_weight = kept.iter().map(|&i| pair(i).0).max().unwrap_or(0);
+
+    let mut reduced = ReducedUnboundedKnapsack {
+        items: kept,
+        capacity,
+        fixed_item: best,
+        fixed_copies: 0,
+    };
+
+    if let Some(best) = best {
+        let best_weight = pair(best).0 as u128;
+        let threshold = best_weight * (max_weight as u128 + 1);
+        if (capacity as u128) > threshold {
+            reduced.fixed_copies = ((capacity as u128 - threshold) / best_weight) as u64;
+            reduced.capacity = capacity - reduced.fixed_copies * pair(best).0;
+        }
+    }
+
+    Ok(reduced)
+}
@@ -0,0 +1,314 @@
+use crate::algorithms_impls::auto::AutoKnapsackSolver;
+use crate::algorithms_impls::bounded_dynamic::BoundedDynamicKnapsackSolver;
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::extended_greedy::ExtendedGreedyKnapsackSolver;
+use crate::algorithms_impls::fptas::FptasKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_recursion::RecursiveKnapsackSolver;
+use crate::algorithms_impls::greedy::GreedyKnapsackSolver;
+use crate::algorithms_impls::lazy_dynamic::LazyDynamicKnapsackSolver;
+use crate::algorithms_impls::meet_in_the_middle::MeetInTheMiddleKnapsackSolver;
+use crate::algorithms_impls::parallel_dynamic::ParallelDynamicKnapsackSolver;
+use crate::algorithms_impls::preprocessed::PreprocessedKnapsackSolver;
+use crate::algorithms_impls::profit_dynamic::ProfitDynamicKnapsackSolver;
+use crate::algorithms_impls::subset_sum::SubsetSumKnapsackSolver;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use std::sync::{OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A function creating a new instance of a knapsack solver.
+pub type SolverFactory = Box<dyn Fn() -> Box<dyn KnapsackSolver> + Send + Sync>;
+
+/// A registered algorithm.
+struct Entry {
+    id: String,             // The stable identifier of the solver
+    name: String,           // The display name of the solver
+    factory: SolverFactory, // Creates new instances of the solver
+}
+
+/// A registry of knapsack solving algorithms.
+///
+/// Algorithms are registered as factories, so every lookup gets a fresh solver instance.
+/// Besides the built-in algorithms, downstream crates can register their own ones at runtime.
+/// An algorithm can be looked up by its identifier, its name or one of its aliases, ignoring case.
+/// The static functions of `AlgorithmsService` use the default registry returned by `global`.
+pub struct AlgorithmsRegistry {
+    entries: Vec<Entry>,            // Registered algorithms, in registration order
+    aliases: Vec<(String, String)>, // Lowercase aliases with the identifiers they refer to
+}
+
+impl AlgorithmsRegistry {
+    /// Creates an empty registry.
+    ///
+    /// Unlike `default`, which registers the built-in algorithms, it lets a caller pick every algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A new `AlgorithmsRegistry` without any algorithms.
+    pub fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+            aliases: Vec::new(),
+        }
+    }
+
+    /// Creates a registry with all built-in algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A new `AlgorithmsRegistry` with the algorithms of this library and their common aliases.
+    pub fn with_builtin_algorithms() -> Self {
+        let mut registry = Self::empty();
+        registry.register(|| Box::new(RecursiveKnapsackSolver));
+        registry.register(|| Box::new(BitMaskKnapsackSolver));
+        registry.register(|| Box::new(DynamicKnapsackSolver));
+        registry.register(|| Box::new(LazyDynamicKnapsackSolver));
+        registry.register(|| Box::new(GreedyKnapsackSolver));
+        registry.register(|| Box::new(ExtendedGreedyKnapsackSolver));
+        registry.register(|| Box::new(MeetInTheMiddleKnapsackSolver));
+        registry.register(|| Box::new(FptasKnapsackSolver::new(0.5)));
+        registry.register(|| Box::new(FptasKnapsackSolver::new(0.1)));
+        registry.register(|| Box::new(FptasKnapsackSolver::new(0.05)));
+        registry.register(|| Box::new(BranchAndBoundKnapsackSolver));
+        registry.register(|| Box::new(BoundedDynamicKnapsackSolver));
+        registry.register(|| Box::new(ProfitDynamicKnapsackSolver));
+        registry.register(|| Box::new(SubsetSumKnapsackSolver));
+        registry.register(|| Box::new(ParallelDynamicKnapsackSolver::new(2)));
+        registry.register(|| Box::new(ParallelDynamicKnapsackSolver::new(4)));
+        registry.register(|| Box::new(ParallelDynamicKnapsackSolver::new(8)));
+        registry.register(|| Box::new(PreprocessedKnapsackSolver::new(Box::new(DynamicKnapsackSolver))));
+        registry.register(|| Box::new(PreprocessedKnapsackSolver::new(Box::new(BranchAndBoundKnapsackSolver))));
+        registry.register(|| Box::new(AutoKnapsackSolver::new()));
+
+        registry.add_alias("dp", "dynamic");
+        registry.add_alias("bitmask", "bit_mask");
+        registry.add_alias("mitm", "meet_in_the_middle");
+        registry.add_alias("bnb", "branch_and_bound");
+        registry
+    }
+
+    /// Returns the default registry shared by the whole program.
+    ///
+    /// It is created with the built-in algorithms on first use.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the lock guarding the default registry.
+    pub fn global() -> &'static RwLock<AlgorithmsRegistry> {
+        static GLOBAL: OnceLock<RwLock<AlgorithmsRegistry>> = OnceLock::new();
+        GLOBAL.get_or_init(|| RwLock::new(AlgorithmsRegistry::with_builtin_algorithms()))
+    }
+
+    /// Locks the default registry for reading.
+    ///
+    /// A poisoned lock is recovered, since the registry is never left in an inconsistent state.
+    pub fn read_global() -> RwLockReadGuard<'static, AlgorithmsRegistry> {
+        Self::global().read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Locks the default registry for writing.
+    ///
+    /// A poisoned lock is recovered, since the registry is never left in an inconsistent state.
+    pub fn write_global() -> RwLockWriteGuard<'static, AlgorithmsRegistry> {
+        Self::global().write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Registers an algorithm.
+    ///
+    /// If an algorithm with the same identifier is already registered, it is replaced and keeps its position.
+    ///
+    /// # Arguments
+    ///
+    /// * `factory` - A function creating new instances of the solver.
+    pub fn register<F>(&mut self, factory: F)
+    where
+        F: Fn() -> Box<dyn KnapsackSolver> + Send + Sync + 'static,
+    {
+        let solver = factory();
+        let entry = Entry {
+            id: solver.get_id(),
+            name: solver.get_name(),
+            factory: Box::new(factory),
+        };
+        match self.entries.iter_mut().find(|registered| registered.id == entry.id) {
+            Some(registered) => *registered = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Adds an alternative name for a registered algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - The alternative name, matched ignoring case.
+    /// * `id` - The identifier of the algorithm.
+    pub fn add_alias(&mut self, alias: &str, id: &str) {
+        let alias = alias.to_lowercase();
+        self.aliases.retain(|(registered, _)| *registered != alias);
+        self.aliases.push((alias, id.to_string()));
+    }
+
+    /// Finds the position of the algorithm with the given identifier, name or alias, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The position of the entry, or `KnapsackError::UnknownAlgorithm` listing the valid names.
+    fn resolve(&self, name: &str) -> Result<usize, KnapsackError> {
+        let algorithms: Vec<(String, String)> = self.entries.iter().map(|entry| (entry.id.clone(), entry.name.clone())).collect();
+        let aliases: Vec<(&str, &str)> = self.aliases.iter().map(|(alias, id)| (alias.as_str(), id.as_str())).collect();
+        resolve_name(name, &algorithms, &aliases)
+    }
+
+    /// Removes the algorithm with the given identifier, name or alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to remove.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the algorithm was registered.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        match self.resolve(name) {
+            Ok(position) => {
+                let id = self.entries.remove(position).id;
+                self.aliases.retain(|(_, aliased)| *aliased != id);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns new instances of all registered algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn KnapsackSolver>>` in registration order.
+    pub fn get_all_algorithms(&self) -> Vec<Box<dyn KnapsackSolver>> {
+        self.entries.iter().map(|entry| (entry.factory)()).collect()
+    }
+
+    /// Returns new instances of the algorithms with the given identifiers, names or aliases.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm_names` - The identifiers, names or aliases of the algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn KnapsackSolver>>` in the order of the names, without duplicates,
+    /// or `KnapsackError::UnknownAlgorithm` for the first unknown name.
+    pub fn get_algorithms_by_names(&self, algorithm_names: &[String]) -> Result<Vec<Box<dyn KnapsackSolver>>, KnapsackError> {
+        let mut positions: Vec<usize> = Vec::with_capacity(algorithm_names.len());
+        for name in algorithm_names {
+            let position = self.resolve(name)?;
+            if !positions.contains(&position) {
+                positions.push(position);
+            }
+        }
+        Ok(positions.into_iter().map(|position| (self.entries[position].factory)()).collect())
+    }
+
+    /// Returns a new instance of the algorithm with the given identifier, name or alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The solver, or `KnapsackError::UnknownAlgorithm` if no algorithm matches the name.
+    pub fn get_algorithm(&self, name: &str) -> Result<Box<dyn KnapsackSolver>, KnapsackError> {
+        self.resolve(name).map(|position| (self.entries[position].factory)())
+    }
+
+    /// Solves the knapsack problem using the algorithm with the given identifier, name or alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm.
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value, or an error if the algorithm is not found or fails.
+    pub fn solve(&self, name: &str, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        self.get_algorithm(name)?.solve(knapsack)
+    }
+
+    /// Solves the knapsack problem using the algorithm with the given identifier, name or alias
+    /// and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm.
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// The selected items, or an error if the algorithm is not found or fails.
+    pub fn solve_with_items(&self, name: &str, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        self.get_algorithm(name)?.solve_with_items(knapsack)
+    }
+
+    /// Returns the names of all registered algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` in registration order.
+    pub fn get_algorithms_names(&self) -> Vec<String> {
+        self.entries.iter().map(|entry| entry.name.clone()).collect()
+    }
+
+    /// Returns the identifiers of all registered algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` in registration order.
+    pub fn get_algorithms_ids(&self) -> Vec<String> {
+        self.entries.iter().map(|entry| entry.id.clone()).collect()
+    }
+}
+
+/// Finds the position of the algorithm with the given identifier, name or alias, ignoring case.
+///
+/// It is shared by the registry and the services of the other knapsack problems, so that all of them
+/// look up algorithms the same way.
+///
+/// # Arguments
+///
+/// * `name` - The identifier, name or alias of the algorithm.
+/// * `algorithms` - The identifiers and names of the algorithms.
+/// * `aliases` - Lowercase aliases with the identifiers they refer to.
+///
+/// # Returns
+///
+/// The position of the algorithm, or `KnapsackError::UnknownAlgorithm` listing the valid names.
+pub(crate) fn resolve_name(name: &str, algorithms: &[(String, String)], aliases: &[(&str, &str)]) -> Result<usize, KnapsackError> {
+    let lowercase = name.to_lowercase();
+    let id = aliases
+        .iter()
+        .find(|(alias, _)| *alias == lowercase)
+        .map_or(lowercase.as_str(), |(_, id)| id);
+
+    algorithms
+        .iter()
+        .position(|(algorithm_id, algorithm_name)| algorithm_id.to_lowercase() == id || algorithm_name.to_lowercase() == lowercase)
+        .ok_or_else(|| KnapsackError::UnknownAlgorithm {
+            name: name.to_string(),
+            valid_names: algorithms.iter().map(|(_, algorithm_name)| algorithm_name.clone()).collect(),
+        })
+}
+
+impl Default for AlgorithmsRegistry {
+    fn default() -> Self {
+        Self::with_builtin_algorithms()
+    }
+}
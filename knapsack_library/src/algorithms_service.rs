@@ -1,10 +1,16 @@
-use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
-use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
-use crate::algorithms_impls::lazy_dynamic::LazyDynamicKnapsackSolver;
-use crate::algorithms_impls::full_iteration_with_recursion::RecursiveKnapsackSolver;
-use crate::algorithms_impls::greedy::GreedyKnapsackSolver;
+use crate::algorithms_impls::auto::AutoKnapsackSolver;
+use crate::algorithms_impls::fractional::FractionalKnapsackSolver;
+use crate::algorithms_registry::AlgorithmsRegistry;
+use crate::models::auto_decision::AutoDecision;
+use crate::models::fractional_knapsack_solution::FractionalKnapsackSolution;
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
 use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::SolveContext;
+use crate::models::solve_observer::SolveObserver;
+use crate::models::solve_outcome::SolveOutcome;
+use std::sync::Arc;
 
 /// A service that manages knapsack solving algorithms.
 ///
@@ -19,30 +25,45 @@ impl AlgorithmsService {
     ///
     /// A `Vec<Box<dyn KnapsackSolver>>` containing boxed instances of all available algorithms.
     pub fn get_all_algorithms() -> Vec<Box<dyn KnapsackSolver>> {
-        vec![
-            Box::new(RecursiveKnapsackSolver),
-            Box::new(BitMaskKnapsackSolver),
-            Box::new(DynamicKnapsackSolver),
-            Box::new(LazyDynamicKnapsackSolver),
-            Box::new(GreedyKnapsackSolver),
-        ]
+        AlgorithmsRegistry::read_global().get_all_algorithms()
     }
 
     /// Returns a list of knapsack-solving algorithms which matches string names.
     ///
+    /// Every name may be an identifier (e.g. "lazy_dynamic"), a display name or an alias, and is matched ignoring case.
+    ///
     /// # Returns
     ///
-    /// A `Vec<Box<dyn KnapsackSolver>>` containing boxed instances of all available algorithms.
-    pub fn get_algorithms_by_names(algorithm_names: Vec<String>) -> Vec<Box<dyn KnapsackSolver>> {
-        let mut algorithms = AlgorithmsService::get_all_algorithms();
-        algorithms.retain(|solver| algorithm_names.contains(&solver.as_ref().get_name()));
-        algorithms
+    /// A `Vec<Box<dyn KnapsackSolver>>` containing boxed instances of the algorithms in the order of the names,
+    /// or `KnapsackError::UnknownAlgorithm` listing the valid names if any of the names is unknown.
+    pub fn get_algorithms_by_names(algorithm_names: Vec<String>) -> Result<Vec<Box<dyn KnapsackSolver>>, KnapsackError> {
+        AlgorithmsRegistry::read_global().get_algorithms_by_names(&algorithm_names)
     }
 
     /// Solves the knapsack problem using the specified algorithm.
     ///
+    /// This method searches for an algorithm by its identifier, name or alias (ignoring case) and uses it to solve the given knapsack problem.
+    /// If no algorithm with the given name is found, it returns `KnapsackError::UnknownAlgorithm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<u64, KnapsackError>` where:
+    /// - `Ok(value)` contains the maximum value that can be achieved for the knapsack.
+    /// - `Err(error)` is returned if the algorithm with the given name is not found or fails.
+    pub fn solve(name: String, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        // The registry lock is released before solving
+        let algorithm = AlgorithmsRegistry::read_global().get_algorithm(&name)?;
+        algorithm.solve(knapsack)
+    }
+
+    /// Solves the knapsack problem using the specified algorithm and returns the selected items.
+    ///
     /// This method searches for an algorithm by its name and uses it to solve the given knapsack problem.
-    /// If no algorithm with the given name is found, it returns `None`.
     ///
     /// # Arguments
     ///
@@ -51,16 +72,112 @@ impl AlgorithmsService {
     ///
     /// # Returns
     ///
-    /// An `Option<u64>` where:
-    /// - `Some(value)` contains the maximum value that can be achieved for the knapsack.
-    /// - `None` is returned if the algorithm with the given name is not found.
-    pub fn solve(name: String, knapsack: &Knapsack) -> Result<u64, String> {
-        for algorithm in AlgorithmsService::get_all_algorithms() {
-            if algorithm.get_name() == name {
-                return algorithm.solve(knapsack);
-            }
-        }
-        Err("Can't find algorithm name".to_owned())
+    /// A `Result<KnapsackSolution, KnapsackError>` where:
+    /// - `Ok(solution)` contains the selected items with their total weight and value.
+    /// - `Err(error)` is returned if the algorithm with the given name is not found or fails.
+    pub fn solve_with_items(name: String, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        let algorithm = AlgorithmsRegistry::read_global().get_algorithm(&name)?;
+        algorithm.solve_with_items(knapsack)
+    }
+
+    /// Solves the knapsack problem using the specified algorithm until the context stops it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    /// * `context` - The deadline and the cancellation token of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<SolveOutcome, KnapsackError>` where:
+    /// - `Ok(outcome)` contains the best solution found and whether the algorithm finished its search.
+    /// - `Err(error)` is returned if the algorithm with the given name is not found, fails,
+    ///   or is stopped without a solution (`KnapsackError::Cancelled`).
+    pub fn solve_with_context(name: String, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        let algorithm = AlgorithmsRegistry::read_global().get_algorithm(&name)?;
+        algorithm.solve_with_context(knapsack, context)
+    }
+
+    /// Solves the knapsack problem using the specified algorithm and reports its progress to the observer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    /// * `observer` - The receiver of the progress events of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<KnapsackSolution, KnapsackError>` where:
+    /// - `Ok(solution)` contains the selected items with their total weight and value.
+    /// - `Err(error)` is returned if the algorithm with the given name is not found or fails.
+    pub fn solve_with_observer(
+        name: String,
+        knapsack: &Knapsack,
+        observer: Arc<dyn SolveObserver>,
+    ) -> Result<KnapsackSolution, KnapsackError> {
+        let context = SolveContext::new().with_observer(observer);
+        Self::solve_with_context(name, knapsack, &context).map(SolveOutcome::into_solution)
+    }
+
+    /// Solves the knapsack problem with an algorithm selected automatically for the knapsack.
+    ///
+    /// The selection depends on the number of items, the capacity, the sum of the values,
+    /// the correlation between the weights and the values and the available memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<(KnapsackSolution, AutoDecision), KnapsackError>` where:
+    /// - `Ok((solution, decision))` contains the selected items and the selected algorithm with the explanation.
+    /// - `Err(error)` is returned if the selected algorithm fails.
+    pub fn solve_auto(knapsack: &Knapsack) -> Result<(KnapsackSolution, AutoDecision), KnapsackError> {
+        AutoKnapsackSolver::new().solve_with_decision(knapsack)
+    }
+
+    /// Registers an algorithm in the default registry.
+    ///
+    /// The algorithm becomes available to all functions of this service. If an algorithm with
+    /// the same name is already registered, it is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `factory` - A function creating new instances of the solver.
+    pub fn register_algorithm<F>(factory: F)
+    where
+        F: Fn() -> Box<dyn KnapsackSolver> + Send + Sync + 'static,
+    {
+        AlgorithmsRegistry::write_global().register(factory);
+    }
+
+    /// Solves the fractional (continuous) knapsack problem.
+    ///
+    /// The value of the fractional knapsack is an upper bound for the value of any
+    /// 0/1 solution, so it can be used to estimate the quality of heuristic results.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `FractionalKnapsackSolution` with the optimal value of the linear programming relaxation,
+    /// or `KnapsackError::ArithmeticOverflow` if the total value of the fitting items doesn't fit in `u64`.
+    pub fn solve_fractional(knapsack: &Knapsack) -> Result<FractionalKnapsackSolution, KnapsackError> {
+        FractionalKnapsackSolver.solve(knapsack)
+    }
+
+    /// Returns the identifiers of all available algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` containing the identifiers of all available algorithms, e.g. "lazy_dynamic".
+    pub fn get_algorithms_ids() -> Vec<String> {
+        AlgorithmsRegistry::read_global().get_algorithms_ids()
     }
 
     /// Returns the names of all available algorithms.
@@ -72,9 +189,6 @@ impl AlgorithmsService {
     ///
     /// A `Vec<String>` containing the names of all available algorithms.
     pub fn get_algorithms_names() -> Vec<String> {
-        AlgorithmsService::get_all_algorithms()
-            .into_iter()
-            .map(|algorithm| algorithm.get_name())
-            .collect()
+        AlgorithmsRegistry::read_global().get_algorithms_names()
     }
 }
@@ -1,5 +1,9 @@
 pub mod models;
 pub mod algorithms_service;
-mod algorithms_impls;
+pub mod algorithms_registry;
+pub mod unbounded_algorithms_service;
+pub mod multi_dimensional_algorithms_service;
+pub mod multiple_algorithms_service;
+pub mod algorithms_impls;
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,49 @@
+/// An algorithm the automatic solver can dispatch to.
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+pub enum AutoChoice {
+    Greedy,
+    BitMask,
+    Dynamic,
+    ProfitDynamic,
+    MeetInTheMiddle,
+    BranchAndBound,
+    BoundedDynamic,
+    SubsetSum,
+    /// No suitable algorithm fits the memory budget, so solving returns `KnapsackError::CapacityTooLarge`.
+    Unsupported,
+}
+
+/// A structure describing which algorithm the automatic solver selected for a knapsack and why.
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub struct AutoDecision {
+    choice: AutoChoice, // The selected algorithm
+    rationale: String,  // A human-readable explanation of the selection
+}
+
+impl AutoDecision {
+    /// Creates a new `AutoDecision`.
+    ///
+    /// # Arguments
+    ///
+    /// * `choice` - The selected algorithm.
+    /// * `rationale` - A human-readable explanation of the selection.
+    ///
+    /// # Returns
+    ///
+    /// A new `AutoDecision` instance.
+    pub fn new(choice: AutoChoice, rationale: String) -> Self {
+        Self { choice, rationale }
+    }
+
+    /// Gets the selected algorithm.
+    pub fn get_choice(&self) -> AutoChoice {
+        self.choice
+    }
+
+    /// Gets the explanation of the selection.
+    pub fn get_rationale(&self) -> &str {
+        &self.rationale
+    }
+}
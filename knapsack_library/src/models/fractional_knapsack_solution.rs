@@ -0,0 +1,129 @@
+use crate::models::knapsack::Knapsack;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a solution of the fractional (continuous) knapsack problem.
+///
+/// Every item is either taken entirely or not at all, except for at most one critical item,
+/// of which only a fraction is taken. The value of this solution is the optimal value of
+/// the linear programming relaxation of the 0/1 knapsack problem.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct FractionalKnapsackSolution {
+    items: Vec<usize>,              // Indices of the entirely taken items, in increasing order
+    critical_item: Option<usize>,   // The item which is taken partially, if any
+    fraction: (u64, u64),           // The taken fraction of the critical item as (numerator, denominator)
+    critical_value: u64,            // The value of the whole critical item
+    integral_value: u64,            // The total value of the entirely taken items
+    total_weight: u64,              // The total weight of the taken items and the part of the critical item
+}
+
+impl FractionalKnapsackSolution {
+    /// Creates a new `FractionalKnapsackSolution` from the taken items of the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` the items were taken from.
+    /// * `items` - Indices of the entirely taken items, in any order.
+    /// * `critical` - The partially taken item and the weight of its taken part, if any.
+    ///
+    /// # Returns
+    ///
+    /// A new `FractionalKnapsackSolution` instance with the specified items.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if any of the indices is out of bounds.
+    pub fn new(knapsack: &Knapsack, mut items: Vec<usize>, critical: Option<(usize, u64)>) -> Self {
+        items.sort_unstable();
+        let items_weight: u64 = items.iter().map(|&i| knapsack.get_item(i).get_weight()).sum();
+        let integral_value = items.iter().map(|&i| knapsack.get_item(i).get_value()).sum();
+
+        let (fraction, critical_value) = match critical {
+            Some((i, weight)) => ((weight, knapsack.get_item(i).get_weight()), knapsack.get_item(i).get_value()),
+            None => ((0, 1), 0),
+        };
+
+        Self {
+            items,
+            critical_item: critical.map(|(i, _)| i),
+            fraction,
+            critical_value,
+            integral_value,
+            total_weight: items_weight + fraction.0,
+        }
+    }
+
+    /// Gets the indices of the entirely taken items.
+    ///
+    /// # Returns
+    ///
+    /// A slice of indices of the entirely taken items in increasing order.
+    pub fn get_items(&self) -> &[usize] {
+        &self.items
+    }
+
+    /// Gets the index of the critical item, which is taken partially.
+    ///
+    /// # Returns
+    ///
+    /// The index of the critical item, or `None` if every item is taken entirely or not at all.
+    pub fn get_critical_item(&self) -> Option<usize> {
+        self.critical_item
+    }
+
+    /// Gets the taken fraction of the critical item as a rational number.
+    ///
+    /// # Returns
+    ///
+    /// The `(numerator, denominator)` pair of the fraction, or `(0, 1)` without a critical item.
+    pub fn get_fraction_ratio(&self) -> (u64, u64) {
+        self.fraction
+    }
+
+    /// Gets the taken fraction of the critical item.
+    ///
+    /// # Returns
+    ///
+    /// The fraction in the range `[0, 1)` as a `f64`.
+    pub fn get_fraction(&self) -> f64 {
+        self.fraction.0 as f64 / self.fraction.1 as f64
+    }
+
+    /// Gets the total weight of the taken items, including the taken part of the critical item.
+    ///
+    /// # Returns
+    ///
+    /// The total weight as a `u64`.
+    pub fn get_total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// Gets the total value of the entirely taken items, without the critical item.
+    ///
+    /// # Returns
+    ///
+    /// The integral part of the value as a `u64`.
+    pub fn get_integral_value(&self) -> u64 {
+        self.integral_value
+    }
+
+    /// Gets the optimal value of the fractional knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The value of the linear programming relaxation as a `f64`.
+    pub fn get_total_value(&self) -> f64 {
+        self.integral_value as f64 + self.critical_value as f64 * self.get_fraction()
+    }
+
+    /// Gets the upper bound for the 0/1 knapsack given by the fractional solution.
+    ///
+    /// # Returns
+    ///
+    /// The value of the fractional knapsack rounded down, computed exactly and saturated at `u64::MAX`.
+    pub fn get_upper_bound(&self) -> u64 {
+        let (numerator, denominator) = self.fraction;
+        self.integral_value.saturating_add((self.critical_value as u128 * numerator as u128 / denominator as u128) as u64)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::models::numeric::Numeric;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing an item of the generic knapsack.
+///
+/// It mirrors `Item` for weights and values of any `Numeric` type (e.g. `u32` for memory-sensitive
+/// instances or `f64` for fractional prices), including the stable identifier. Every item is considered
+/// only once.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct GenericItem<T> {
+    weight: T,  // The weight of the item
+    value: T,   // The value of the item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<u64>,  // The stable identifier of the item
+}
+
+impl<T: Numeric> GenericItem<T> {
+    /// Creates a new `GenericItem` with the given weight and value.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - The weight of the item.
+    /// * `value` - The value of the item.
+    ///
+    /// # Returns
+    ///
+    /// A new `GenericItem` instance with the specified weight and value.
+    pub fn new(weight: T, value: T) -> Self {
+        Self { weight, value, id: None }
+    }
+
+    /// Gets the weight of the item.
+    ///
+    /// # Returns
+    ///
+    /// The weight of the item as a `T`.
+    pub fn get_weight(&self) -> T {
+        self.weight
+    }
+
+    /// Gets the value of the item.
+    ///
+    /// # Returns
+    ///
+    /// The value of the item as a `T`.
+    pub fn get_value(&self) -> T {
+        self.value
+    }
+
+    /// Sets the stable identifier of the item.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the item.
+    ///
+    /// # Returns
+    ///
+    /// The `GenericItem` with the specified identifier.
+    pub fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Gets the stable identifier of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier of the item, or `None` if it wasn't set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+}
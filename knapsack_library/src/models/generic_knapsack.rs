@@ -0,0 +1,113 @@
+use crate::models::generic_item::GenericItem;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::numeric::Numeric;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a knapsack with weights and values of any `Numeric` type.
+///
+/// It mirrors `Knapsack` for the generic solvers.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct GenericKnapsack<T> {
+    capacity: T,                // The capacity of the knapsack (maximum weight it can hold)
+    items: Vec<GenericItem<T>>, // A list of items that can be placed in the knapsack
+}
+
+impl<T: Numeric> GenericKnapsack<T> {
+    /// Creates a new `GenericKnapsack` with the given capacity and list of items.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The capacity of the knapsack, i.e., the maximum weight it can carry.
+    /// * `items` - A `Vec<GenericItem<T>>` representing the items that can be placed in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A new `GenericKnapsack` instance with the specified capacity and items.
+    pub fn new(capacity: T, items: Vec<GenericItem<T>>) -> Self {
+        Self { capacity, items }
+    }
+
+    /// Gets the number of items in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The number of items as a `usize`.
+    pub fn get_items_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Gets a reference to the item at the specified index in the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `GenericItem` at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item(&self, index: usize) -> &GenericItem<T> {
+        &self.items[index]
+    }
+
+    /// Gets the stable identifier of the item at the specified index (see `Knapsack::get_item_id`).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier set with `GenericItem::with_id`, or `None` if none was set.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item_id(&self, index: usize) -> Option<u64> {
+        self.items[index].get_id()
+    }
+
+    /// Gets the capacity of the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The capacity of the knapsack as a `T`.
+    pub fn get_capacity(&self) -> T {
+        self.capacity
+    }
+
+    /// Checks that the knapsack can be solved: the capacity, weights and values must be non-negative
+    /// and finite, and the total value of the items fitting in the knapsack must fit in `T`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the knapsack is valid, `KnapsackError::InvalidInstance` if a number is negative
+    /// or not finite, or `KnapsackError::ArithmeticOverflow` if the total value doesn't fit in `T`.
+    pub fn validate(&self) -> Result<(), KnapsackError> {
+        if !self.capacity.is_valid() {
+            return Err(KnapsackError::InvalidInstance(format!("invalid capacity {:?}", self.capacity)));
+        }
+
+        let mut total = T::zero();
+        for (i, item) in self.items.iter().enumerate() {
+            if !item.get_weight().is_valid() || !item.get_value().is_valid() {
+                return Err(KnapsackError::InvalidInstance(format!(
+                    "item {} has an invalid weight {:?} or value {:?}",
+                    i,
+                    item.get_weight(),
+                    item.get_value()
+                )));
+            }
+            if item.get_weight() <= self.capacity {
+                total = total.checked_add(item.get_value()).ok_or(KnapsackError::ArithmeticOverflow)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::numeric::Numeric;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a solution of the generic knapsack problem.
+///
+/// This structure contains the indices and the identifiers of the selected items along with their
+/// total weight and value.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct GenericKnapsackSolution<T> {
+    items: Vec<usize>,  // Indices of the selected items in the knapsack, in increasing order
+    #[serde(default)]
+    item_ids: Vec<Option<u64>>, // Identifiers of the selected items, in the order of their indices
+    total_weight: T,    // The total weight of the selected items
+    total_value: T,     // The total value of the selected items
+}
+
+impl<T: Numeric> GenericKnapsackSolution<T> {
+    /// Creates a new `GenericKnapsackSolution` from the selected items of the knapsack.
+    ///
+    /// The total weight and value are computed from the items of the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `GenericKnapsack` the items were selected from.
+    /// * `items` - Indices of the selected items, in any order.
+    ///
+    /// # Returns
+    ///
+    /// A new `GenericKnapsackSolution` instance with the specified items.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if any of the indices is out of bounds.
+    pub fn new(knapsack: &GenericKnapsack<T>, mut items: Vec<usize>) -> Self {
+        items.sort_unstable();
+        let total_weight = items.iter().fold(T::zero(), |sum, &i| sum + knapsack.get_item(i).get_weight());
+        let total_value = items.iter().fold(T::zero(), |sum, &i| sum + knapsack.get_item(i).get_value());
+        let item_ids = items.iter().map(|&i| knapsack.get_item_id(i)).collect();
+
+        Self {
+            items,
+            item_ids,
+            total_weight,
+            total_value,
+        }
+    }
+
+    /// Gets the indices of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// A slice of indices of the selected items in increasing order.
+    pub fn get_items(&self) -> &[usize] {
+        &self.items
+    }
+
+    /// Gets the identifiers of the selected items (see `GenericKnapsack::get_item_id`).
+    ///
+    /// # Returns
+    ///
+    /// A slice of identifiers in the same order as the indices of `get_items`, with `None` for the items without an identifier.
+    pub fn get_item_ids(&self) -> &[Option<u64>] {
+        &self.item_ids
+    }
+
+    /// Gets the total weight of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// The total weight as a `T`.
+    pub fn get_total_weight(&self) -> T {
+        self.total_weight
+    }
+
+    /// Gets the total value of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// The total value as a `T`.
+    pub fn get_total_value(&self) -> T {
+        self.total_value
+    }
+}
@@ -0,0 +1,41 @@
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::generic_knapsack_solution::GenericKnapsackSolution;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::numeric::Numeric;
+
+/// A trait for solving the knapsack problem with weights and values of the numeric type `T`.
+///
+/// It mirrors `KnapsackSolver` for `GenericKnapsack`. Solvers implement it for the types they support,
+/// e.g. the dynamic programming only for `Integer` types, so using them with other types doesn't compile.
+pub trait GenericKnapsackSolver<T: Numeric> {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the name of the algorithm (e.g., "Generic greedy").
+    fn get_name(&self) -> String;
+
+    /// Solves the knapsack problem for the given `GenericKnapsack`.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `GenericKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value found by the algorithm.
+    fn solve(&self, knapsack: &GenericKnapsack<T>) -> Result<T, KnapsackError> {
+        self.solve_with_items(knapsack).map(|solution| solution.get_total_value())
+    }
+
+    /// Solves the knapsack problem and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `GenericKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// A `GenericKnapsackSolution` with the selected items.
+    fn solve_with_items(&self, knapsack: &GenericKnapsack<T>) -> Result<GenericKnapsackSolution<T>, KnapsackError>;
+}
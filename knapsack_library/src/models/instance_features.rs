@@ -0,0 +1,139 @@
+use crate::models::knapsack::Knapsack;
+
+/// A structure describing the properties of a knapsack which matter for choosing an algorithm.
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub struct InstanceFeatures {
+    items_len: usize,   // The number of items
+    capacity: u64,      // The capacity of the knapsack
+    weight_sum: u128,   // The total weight of all copies of the items
+    profit_sum: u128,   // The sum of the values of the items which fit in the knapsack alone
+    correlation: f64,   // The Pearson correlation between the weights and the values of the items
+    has_copies: bool,   // Whether any item may be taken more than once
+    subset_sum: bool,   // Whether the values of the items are proportional to their weights
+}
+
+impl InstanceFeatures {
+    /// Creates a new `InstanceFeatures` with the given properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `items_len` - The number of items.
+    /// * `capacity` - The capacity of the knapsack.
+    /// * `weight_sum` - The total weight of all copies of the items.
+    /// * `profit_sum` - The sum of the values of the items which fit in the knapsack alone.
+    /// * `correlation` - The correlation between the weights and the values, from -1 to 1.
+    /// * `has_copies` - Whether any item may be taken more than once.
+    /// * `subset_sum` - Whether the values of the items are proportional to their weights.
+    ///
+    /// # Returns
+    ///
+    /// A new `InstanceFeatures` instance with the specified properties.
+    pub fn new(
+        items_len: usize,
+        capacity: u64,
+        weight_sum: u128,
+        profit_sum: u128,
+        correlation: f64,
+        has_copies: bool,
+        subset_sum: bool,
+    ) -> Self {
+        Self {
+            items_len,
+            capacity,
+            weight_sum,
+            profit_sum,
+            correlation,
+            has_copies,
+            subset_sum,
+        }
+    }
+
+    /// Computes the features of a knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` to inspect.
+    ///
+    /// # Returns
+    ///
+    /// The `InstanceFeatures` of the knapsack.
+    pub fn from_knapsack(knapsack: &Knapsack) -> Self {
+        let capacity = knapsack.get_capacity();
+        let items: Vec<_> = (0..knapsack.get_items_len()).map(|i| knapsack.get_item(i)).collect();
+
+        // The sum saturates, since a few items with u64::MAX copies of u64::MAX weights overflow even u128
+        let weight_sum = items
+            .iter()
+            .fold(0u128, |sum, item| sum.saturating_add(item.get_weight() as u128 * item.get_count() as u128));
+        let profit_sum = items
+            .iter()
+            .filter(|item| item.get_weight() <= capacity)
+            .map(|item| item.get_value() as u128)
+            .sum();
+
+        // The correlation is undefined for less than two items or equal weights or values, it is taken as zero then
+        let mut correlation = 0.0;
+        if items.len() > 1 {
+            let n = items.len() as f64;
+            let mean_weight = items.iter().map(|item| item.get_weight() as f64).sum::<f64>() / n;
+            let mean_value = items.iter().map(|item| item.get_value() as f64).sum::<f64>() / n;
+            let (mut covariance, mut weight_variance, mut value_variance) = (0.0, 0.0, 0.0);
+            for item in &items {
+                let dw = item.get_weight() as f64 - mean_weight;
+                let dv = item.get_value() as f64 - mean_value;
+                covariance += dw * dv;
+                weight_variance += dw * dw;
+                value_variance += dv * dv;
+            }
+            if weight_variance > 0.0 && value_variance > 0.0 {
+                correlation = covariance / (weight_variance * value_variance).sqrt();
+            }
+        }
+
+        Self {
+            items_len: items.len(),
+            capacity,
+            weight_sum,
+            profit_sum,
+            correlation,
+            has_copies: items.iter().any(|item| item.get_count() > 1),
+            subset_sum: knapsack.has_proportional_values(),
+        }
+    }
+
+    /// Gets the number of items.
+    pub fn get_items_len(&self) -> usize {
+        self.items_len
+    }
+
+    /// Gets the capacity of the knapsack.
+    pub fn get_capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// Gets the total weight of all copies of the items.
+    pub fn get_weight_sum(&self) -> u128 {
+        self.weight_sum
+    }
+
+    /// Gets the sum of the values of the items which fit in the knapsack alone.
+    pub fn get_profit_sum(&self) -> u128 {
+        self.profit_sum
+    }
+
+    /// Gets the Pearson correlation between the weights and the values of the items.
+    pub fn get_correlation(&self) -> f64 {
+        self.correlation
+    }
+
+    /// Checks whether any item may be taken more than once.
+    pub fn has_copies(&self) -> bool {
+        self.has_copies
+    }
+
+    /// Checks whether the values of the items are proportional to their weights.
+    pub fn is_subset_sum(&self) -> bool {
+        self.subset_sum
+    }
+}
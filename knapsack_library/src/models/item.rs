@@ -3,12 +3,26 @@ use serde::{Serialize, Deserialize};
 /// A structure representing an item in the knapsack.
 ///
 /// This structure contains the weight and value of an item that can be placed in the knapsack.
+/// An item may also carry the maximum number of its copies for the bounded knapsack problem;
+/// 0/1 solvers consider every item only once. An item may carry a stable identifier and a label,
+/// so that it can be recognized after the items were reordered or preprocessed.
 #[derive(Clone, Debug)]
 #[derive(Serialize, Deserialize)]
 #[derive(PartialEq)]
 pub struct Item {
     weight: u64,  // The weight of the item
     value: u64,   // The value of the item
+    #[serde(default = "default_count")]
+    count: u64,   // The maximum number of copies of the item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<u64>,       // The stable identifier of the item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>, // The user label of the item (e.g. the name of the real-world object)
+}
+
+// Default value for "count", so that plain 0/1 items keep being deserialized
+fn default_count() -> u64 {
+    1
 }
 
 impl Item {
@@ -23,7 +37,22 @@ impl Item {
     ///
     /// A new `Item` instance with the specified weight and value.
     pub fn new(weight: u64, value: u64) -> Self {
-        Self { weight, value }
+        Self { weight, value, count: 1, id: None, label: None }
+    }
+
+    /// Creates a new `Item` that can be taken up to `count` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - The weight of one copy of the item.
+    /// * `value` - The value of one copy of the item.
+    /// * `count` - The maximum number of copies of the item.
+    ///
+    /// # Returns
+    ///
+    /// A new `Item` instance with the specified weight, value and count.
+    pub fn with_count(weight: u64, value: u64, count: u64) -> Self {
+        Self { weight, value, count, id: None, label: None }
     }
 
     /// Gets the weight of the item.
@@ -43,4 +72,66 @@ impl Item {
     pub fn get_value(&self) -> u64 {
         self.value
     }
+
+    /// Gets the maximum number of copies of the item.
+    ///
+    /// # Returns
+    ///
+    /// The count of the item as a `u64`, which is 1 for 0/1 items.
+    pub fn get_count(&self) -> u64 {
+        self.count
+    }
+
+    /// Sets the stable identifier of the item.
+    ///
+    /// Solutions report the selected items by their identifiers (see `Knapsack::get_item_id`).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the item.
+    ///
+    /// # Returns
+    ///
+    /// The `Item` with the specified identifier.
+    pub fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the user label of the item.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the item, e.g. the name of the real-world object.
+    ///
+    /// # Returns
+    ///
+    /// The `Item` with the specified label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Gets the stable identifier of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier of the item, or `None` if it wasn't set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Gets the user label of the item.
+    ///
+    /// # Returns
+    ///
+    /// The label of the item, or `None` if it wasn't set.
+    pub fn get_label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Creates a copy of the item with another weight, keeping its count, identifier and label.
+    pub(crate) fn with_weight(&self, weight: u64) -> Self {
+        Self { weight, ..self.clone() }
+    }
 }
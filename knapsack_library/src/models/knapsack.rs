@@ -1,4 +1,5 @@
 use crate::models::item::Item;
+use crate::models::knapsack_error::KnapsackError;
 use serde::{Serialize, Deserialize};
 
 /// A structure representing a knapsack for the algorithms.
@@ -56,6 +57,24 @@ impl Knapsack {
         &self.items[index]
     }
 
+    /// Gets the stable identifier of the item at the specified index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier set with `Item::with_id`, or `None` if none was set. Identifiers are opaque
+    /// and never derived from indices, so an unset one can't collide with an explicit one.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item_id(&self, index: usize) -> Option<u64> {
+        self.items[index].get_id()
+    }
+
     /// Gets the capacity of the knapsack.
     ///
     /// # Returns
@@ -64,4 +83,55 @@ impl Knapsack {
     pub fn get_capacity(&self) -> u64 {
         self.capacity
     }
+
+    /// Checks whether the values of the items are proportional to their weights, i.e. the knapsack
+    /// is a subset-sum instance.
+    ///
+    /// Only the items that fit in the knapsack are considered, and items without weight must have no value.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the values are proportional to the weights.
+    pub fn has_proportional_values(&self) -> bool {
+        let mut reference: Option<(u128, u128)> = None;
+
+        for item in self.items.iter().filter(|item| item.get_weight() <= self.capacity) {
+            let (weight, value) = (item.get_weight() as u128, item.get_value() as u128);
+            if weight == 0 {
+                if value > 0 {
+                    return false;
+                }
+                continue;
+            }
+            match reference {
+                // The ratios are compared by cross-multiplication to stay exact
+                Some((w0, v0)) if value * w0 != v0 * weight => return false,
+                Some(_) => {}
+                None => reference = Some((weight, value)),
+            }
+        }
+        true
+    }
+
+    /// Checks that the total value of any selection of items fits in `u64`.
+    ///
+    /// The solvers add up values with plain arithmetic, so they call this check first. It only fails
+    /// if the total value of all copies of the items fitting in the knapsack exceeds `u64::MAX`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if no sum of values can overflow, or `KnapsackError::ArithmeticOverflow` otherwise.
+    pub fn check_overflow(&self) -> Result<(), KnapsackError> {
+        // Even u128 overflows for a few items with u64::MAX copies of u64::MAX values
+        let total = self
+            .items
+            .iter()
+            .filter(|item| item.get_weight() <= self.capacity)
+            .try_fold(0u128, |total, item| total.checked_add(item.get_value() as u128 * item.get_count() as u128))
+            .ok_or(KnapsackError::ArithmeticOverflow)?;
+        if total > u64::MAX as u128 {
+            return Err(KnapsackError::ArithmeticOverflow);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error reported by the knapsack solvers and the algorithm services.
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq)]
+pub enum KnapsackError {
+    /// No algorithm with the given name is available; `valid_names` lists the available ones.
+    UnknownAlgorithm { name: String, valid_names: Vec<String> },
+    /// The solver supports at most `max` items, but the knapsack has `items`.
+    TooManyItems { items: usize, max: usize },
+    /// The capacity, or another size of a dynamic programming table, is too large to process.
+    CapacityTooLarge,
+    /// An intermediate result doesn't fit in the numeric type.
+    ArithmeticOverflow,
+    /// The knapsack is malformed; the message describes the problem.
+    InvalidInstance(String),
+    /// A parameter of the solver is out of its range; the message describes the problem.
+    InvalidParameter(String),
+    /// The knapsack has no solution, e.g. no combination of items fills it exactly.
+    Infeasible,
+    /// The solver was stopped before it finished.
+    Cancelled,
+}
+
+impl fmt::Display for KnapsackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnapsackError::UnknownAlgorithm { name, valid_names } => {
+                write!(f, "Can't find algorithm name: {} (valid names: {})", name, valid_names.join(", "))
+            }
+            KnapsackError::TooManyItems { items, max } => {
+                write!(f, "The number of items ({}) exceeds the maximum allowed ({})", items, max)
+            }
+            KnapsackError::CapacityTooLarge => write!(f, "Capacity too large to process"),
+            KnapsackError::ArithmeticOverflow => write!(f, "Arithmetic overflow"),
+            KnapsackError::InvalidInstance(message) => write!(f, "Invalid knapsack: {}", message),
+            KnapsackError::InvalidParameter(message) => write!(f, "Invalid parameter: {}", message),
+            KnapsackError::Infeasible => write!(f, "No combination of items satisfies the constraints"),
+            KnapsackError::Cancelled => write!(f, "The solver was cancelled"),
+        }
+    }
+}
+
+impl Error for KnapsackError {}
@@ -1,4 +1,9 @@
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::solve_context::SolveContext;
+use crate::models::solve_outcome::SolveOutcome;
+use crate::models::solver_capabilities::SolverCapabilities;
 
 /// A trait for solving the knapsack problem.
 ///
@@ -13,6 +18,31 @@ pub trait KnapsackSolver {
     /// A string representing the name of the algorithm (e.g., "Recursion", "Dynamic Programming").
     fn get_name(&self) -> String;
 
+    /// Returns the stable machine identifier of the algorithm.
+    ///
+    /// Unlike the name, the identifier is meant for configuration files and file paths, and it
+    /// doesn't change when the name is reworded. By default it is derived from the name with `name_to_id`,
+    /// so solvers whose names may change should override it.
+    ///
+    /// # Returns
+    ///
+    /// A lowercase identifier with underscores (e.g., "lazy_dynamic").
+    fn get_id(&self) -> String {
+        name_to_id(&self.get_name())
+    }
+
+    /// Returns the metadata describing what the algorithm can do.
+    ///
+    /// Callers can use it to find out whether the algorithm is exact and whether it supports
+    /// a given knapsack (see `SolverCapabilities::check`) before solving it.
+    ///
+    /// # Returns
+    ///
+    /// The `SolverCapabilities` of the algorithm. By default nothing is promised.
+    fn get_capabilities(&self) -> SolverCapabilities {
+        SolverCapabilities::unknown()
+    }
+
     /// Solves the knapsack problem for the given `Knapsack`.
     ///
     /// This method is responsible for finding the optimal combination of items
@@ -25,5 +55,64 @@ pub trait KnapsackSolver {
     /// # Returns
     ///
     /// The maximum value that can be achieved by selecting items from the knapsack.
-    fn solve(&self, knapsack: &Knapsack) -> Result<u64, String>;
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError>;
+
+    /// Solves the knapsack problem for the given `Knapsack` and returns the selected items.
+    ///
+    /// The value of the returned solution is the same as the one returned by `solve`,
+    /// but solvers may need more memory to remember which items were taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` with the selected items, their total weight and total value.
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError>;
+
+    /// Solves the knapsack problem for the given `Knapsack` until the context stops the solver.
+    ///
+    /// Solvers which keep the best solution found so far (e.g. full iteration and branch and bound)
+    /// check the context periodically and return that solution marked as not proven when stopped.
+    /// By default the context is only checked before solving, and a stopped context gives `KnapsackError::Cancelled`.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` instance to solve the problem for.
+    /// * `context` - The deadline and the cancellation token of the solver.
+    ///
+    /// # Returns
+    ///
+    /// A `SolveOutcome` with the best solution found and whether the solver finished its search.
+    fn solve_with_context(&self, knapsack: &Knapsack, context: &SolveContext) -> Result<SolveOutcome, KnapsackError> {
+        if context.is_stopped() {
+            return Err(KnapsackError::Cancelled);
+        }
+        self.solve_with_items(knapsack).map(|solution| SolveOutcome::new(solution, true))
+    }
+}
+
+/// Derives a machine identifier from an algorithm name.
+///
+/// The name is lowercased and every run of characters other than ASCII letters and digits
+/// is replaced by a single underscore, e.g. "FPTAS 0.5" becomes "fptas_0_5".
+///
+/// # Arguments
+///
+/// * `name` - The name of the algorithm.
+///
+/// # Returns
+///
+/// The identifier as a `String`.
+pub fn name_to_id(name: &str) -> String {
+    let mut id = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c.to_ascii_lowercase());
+        } else if !id.is_empty() && !id.ends_with('_') {
+            id.push('_');
+        }
+    }
+    id.trim_end_matches('_').to_string()
 }
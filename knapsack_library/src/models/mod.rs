@@ -1,3 +1,28 @@
 pub mod knapsack;
 pub mod item;
-pub mod knapsack_solver;
\ No newline at end of file
+pub mod knapsack_solver;
+pub mod knapsack_error;
+pub mod solver_capabilities;
+pub mod knapsack_solution;
+pub mod solve_context;
+pub mod solve_observer;
+pub mod solve_outcome;
+pub mod fractional_knapsack_solution;
+pub mod reduced_knapsack;
+pub mod instance_features;
+pub mod auto_decision;
+pub mod unbounded_knapsack;
+pub mod unbounded_knapsack_solution;
+pub mod unbounded_knapsack_solver;
+pub mod multi_dimensional_item;
+pub mod multi_dimensional_knapsack;
+pub mod multi_dimensional_knapsack_solution;
+pub mod multi_dimensional_knapsack_solver;
+pub mod multiple_knapsack;
+pub mod multiple_knapsack_solution;
+pub mod multiple_knapsack_solver;
+pub mod numeric;
+pub mod generic_item;
+pub mod generic_knapsack;
+pub mod generic_knapsack_solution;
+pub mod generic_knapsack_solver;
\ No newline at end of file
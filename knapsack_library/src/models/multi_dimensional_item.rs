@@ -0,0 +1,73 @@
+use serde::{Serialize, Deserialize};
+
+/// A structure representing an item of the multi-dimensional knapsack.
+///
+/// This structure contains a weight for every capacity constraint of the knapsack
+/// (e.g. weight, volume and cost) and the value of the item. Like `Item`, it may carry
+/// a stable identifier.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct MultiDimensionalItem {
+    weights: Vec<u64>,  // The weights of the item, one per dimension
+    value: u64,         // The value of the item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<u64>,    // The stable identifier of the item
+}
+
+impl MultiDimensionalItem {
+    /// Creates a new `MultiDimensionalItem` with the given weights and value.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weights of the item, one per dimension of the knapsack.
+    /// * `value` - The value of the item.
+    ///
+    /// # Returns
+    ///
+    /// A new `MultiDimensionalItem` instance with the specified weights and value.
+    pub fn new(weights: Vec<u64>, value: u64) -> Self {
+        Self { weights, value, id: None }
+    }
+
+    /// Gets the weights of the item.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the weight of the item in every dimension.
+    pub fn get_weights(&self) -> &[u64] {
+        &self.weights
+    }
+
+    /// Gets the value of the item.
+    ///
+    /// # Returns
+    ///
+    /// The value of the item as a `u64`.
+    pub fn get_value(&self) -> u64 {
+        self.value
+    }
+
+    /// Sets the stable identifier of the item.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the item.
+    ///
+    /// # Returns
+    ///
+    /// The `MultiDimensionalItem` with the specified identifier.
+    pub fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Gets the stable identifier of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier of the item, or `None` if it wasn't set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+}
@@ -0,0 +1,141 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_item::MultiDimensionalItem;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a multi-dimensional knapsack for the algorithms.
+///
+/// This structure contains several capacities of the knapsack (e.g. weight, volume and cost limits)
+/// and a list of items, each of which has a weight for every capacity.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct MultiDimensionalKnapsack {
+    capacities: Vec<u64>,              // The capacities of the knapsack, one per dimension
+    items: Vec<MultiDimensionalItem>,  // A list of items that can be placed in the knapsack
+}
+
+impl MultiDimensionalKnapsack {
+    /// Creates a new `MultiDimensionalKnapsack` with the given capacities and list of items.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacities` - The capacities of the knapsack, one per dimension.
+    /// * `items` - A `Vec<MultiDimensionalItem>` representing the items that can be placed in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A new `MultiDimensionalKnapsack` instance, or an error if the knapsack is invalid (see `validate`).
+    pub fn new(capacities: Vec<u64>, items: Vec<MultiDimensionalItem>) -> Result<Self, KnapsackError> {
+        let knapsack = Self { capacities, items };
+        knapsack.validate()?;
+        Ok(knapsack)
+    }
+
+    /// Checks that the knapsack has at least one dimension and that every item has a weight per dimension.
+    ///
+    /// Knapsacks created with `new` are always valid, but deserialized ones have to be checked.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the knapsack is valid, or an error describing the first mismatch.
+    pub fn validate(&self) -> Result<(), KnapsackError> {
+        if self.capacities.is_empty() {
+            return Err(KnapsackError::InvalidInstance(
+                "the knapsack must have at least one capacity".to_string(),
+            ));
+        }
+
+        for (i, item) in self.items.iter().enumerate() {
+            if item.get_weights().len() != self.capacities.len() {
+                return Err(KnapsackError::InvalidInstance(format!(
+                    "item {} has {} weights, but the knapsack has {} capacities",
+                    i,
+                    item.get_weights().len(),
+                    self.capacities.len()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the number of dimensions of the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The number of capacities as a `usize`.
+    pub fn get_dimensions(&self) -> usize {
+        self.capacities.len()
+    }
+
+    /// Gets the number of items in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The number of items currently in the knapsack as a `usize`.
+    pub fn get_items_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Gets a reference to the item at the specified index in the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `MultiDimensionalItem` at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item(&self, index: usize) -> &MultiDimensionalItem {
+        &self.items[index]
+    }
+
+    /// Gets the stable identifier of the item at the specified index (see `Knapsack::get_item_id`).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier set with `MultiDimensionalItem::with_id`, or `None` if none was set.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item_id(&self, index: usize) -> Option<u64> {
+        self.items[index].get_id()
+    }
+
+    /// Gets the capacities of the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the capacity of the knapsack in every dimension.
+    pub fn get_capacities(&self) -> &[u64] {
+        &self.capacities
+    }
+
+    /// Checks that the total value of any selection of items fits in `u64`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if no sum of values can overflow, or `KnapsackError::ArithmeticOverflow` if the total value
+    /// of the items fitting in the knapsack exceeds `u64::MAX`.
+    pub fn check_overflow(&self) -> Result<(), KnapsackError> {
+        let total: u128 = self
+            .items
+            .iter()
+            .filter(|item| item.get_weights().iter().zip(&self.capacities).all(|(w, c)| w <= c))
+            .map(|item| item.get_value() as u128)
+            .sum();
+        if total > u64::MAX as u128 {
+            return Err(KnapsackError::ArithmeticOverflow);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a solution of the multi-dimensional knapsack problem.
+///
+/// This structure contains the indices and the identifiers of the selected items along with their
+/// total weights and value.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct MultiDimensionalKnapsackSolution {
+    items: Vec<usize>,       // Indices of the selected items in the knapsack, in increasing order
+    #[serde(default)]
+    item_ids: Vec<Option<u64>>, // Identifiers of the selected items, in the order of their indices
+    total_weights: Vec<u64>, // The total weights of the selected items, one per dimension
+    total_value: u64,        // The total value of the selected items
+}
+
+impl MultiDimensionalKnapsackSolution {
+    /// Creates a new `MultiDimensionalKnapsackSolution` from the selected items of the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `MultiDimensionalKnapsack` the items were selected from.
+    /// * `items` - Indices of the selected items, in any order.
+    ///
+    /// # Returns
+    ///
+    /// A new `MultiDimensionalKnapsackSolution` instance with the specified items.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if any of the indices is out of bounds.
+    pub fn new(knapsack: &MultiDimensionalKnapsack, mut items: Vec<usize>) -> Self {
+        items.sort_unstable();
+        let mut total_weights = vec![0; knapsack.get_dimensions()];
+        let mut total_value = 0;
+
+        for &i in &items {
+            let item = knapsack.get_item(i);
+            for (total, weight) in total_weights.iter_mut().zip(item.get_weights()) {
+                *total += weight;
+            }
+            total_value += item.get_value();
+        }
+        let item_ids = items.iter().map(|&i| knapsack.get_item_id(i)).collect();
+
+        Self {
+            items,
+            item_ids,
+            total_weights,
+            total_value,
+        }
+    }
+
+    /// Gets the indices of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// A slice of indices of the selected items in increasing order.
+    pub fn get_items(&self) -> &[usize] {
+        &self.items
+    }
+
+    /// Gets the identifiers of the selected items (see `MultiDimensionalKnapsack::get_item_id`).
+    ///
+    /// # Returns
+    ///
+    /// A slice of identifiers in the same order as the indices of `get_items`, with `None` for the items without an identifier.
+    pub fn get_item_ids(&self) -> &[Option<u64>] {
+        &self.item_ids
+    }
+
+    /// Gets the total weights of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the total weight of the selected items in every dimension.
+    pub fn get_total_weights(&self) -> &[u64] {
+        &self.total_weights
+    }
+
+    /// Gets the total value of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// The total value as a `u64`.
+    pub fn get_total_value(&self) -> u64 {
+        self.total_value
+    }
+}
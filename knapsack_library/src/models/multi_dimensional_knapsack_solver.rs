@@ -0,0 +1,39 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solution::MultiDimensionalKnapsackSolution;
+
+/// A trait for solving the multi-dimensional knapsack problem.
+///
+/// All multi-dimensional knapsack-solving algorithms should implement this trait. It mirrors
+/// `KnapsackSolver` for knapsacks with several capacity constraints.
+pub trait MultiDimensionalKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the name of the algorithm (e.g., "Multi-dimensional greedy").
+    fn get_name(&self) -> String;
+
+    /// Solves the multi-dimensional knapsack problem for the given `MultiDimensionalKnapsack`.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `MultiDimensionalKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value that can be achieved without exceeding any of the capacities,
+    /// or an error if the knapsack is invalid.
+    fn solve(&self, knapsack: &MultiDimensionalKnapsack) -> Result<u64, KnapsackError>;
+
+    /// Solves the multi-dimensional knapsack problem and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `MultiDimensionalKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// A `MultiDimensionalKnapsackSolution` with the selected items, their total weights and value.
+    fn solve_with_items(&self, knapsack: &MultiDimensionalKnapsack) -> Result<MultiDimensionalKnapsackSolution, KnapsackError>;
+}
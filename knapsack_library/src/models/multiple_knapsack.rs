@@ -0,0 +1,112 @@
+use crate::models::item::Item;
+use crate::models::knapsack_error::KnapsackError;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a multiple knapsack problem for the algorithms.
+///
+/// This structure contains the capacities of several knapsacks and a list of items.
+/// Every item can be placed in at most one of the knapsacks.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct MultipleKnapsack {
+    capacities: Vec<u64>,  // The capacities of the knapsacks
+    items: Vec<Item>,      // A list of items that can be placed in the knapsacks
+}
+
+impl MultipleKnapsack {
+    /// Creates a new `MultipleKnapsack` with the given capacities and list of items.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacities` - The capacities of the knapsacks, one per knapsack.
+    /// * `items` - A `Vec<Item>` representing the items that can be placed in the knapsacks.
+    ///
+    /// # Returns
+    ///
+    /// A new `MultipleKnapsack` instance with the specified capacities and items.
+    pub fn new(capacities: Vec<u64>, items: Vec<Item>) -> Self {
+        Self { capacities, items }
+    }
+
+    /// Gets the number of knapsacks.
+    ///
+    /// # Returns
+    ///
+    /// The number of knapsacks as a `usize`.
+    pub fn get_knapsacks_len(&self) -> usize {
+        self.capacities.len()
+    }
+
+    /// Gets the capacities of the knapsacks.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the capacity of every knapsack.
+    pub fn get_capacities(&self) -> &[u64] {
+        &self.capacities
+    }
+
+    /// Gets the number of items.
+    ///
+    /// # Returns
+    ///
+    /// The number of items as a `usize`.
+    pub fn get_items_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Gets a reference to the item at the specified index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `Item` at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item(&self, index: usize) -> &Item {
+        &self.items[index]
+    }
+
+    /// Gets the stable identifier of the item at the specified index (see `Knapsack::get_item_id`).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier set with `Item::with_id`, or `None` if none was set.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item_id(&self, index: usize) -> Option<u64> {
+        self.items[index].get_id()
+    }
+
+    /// Checks that the total value of any assignment of items fits in `u64`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if no sum of values can overflow, or `KnapsackError::ArithmeticOverflow` if the total value
+    /// of the items fitting in some knapsack exceeds `u64::MAX`.
+    pub fn check_overflow(&self) -> Result<(), KnapsackError> {
+        let max_capacity = self.capacities.iter().copied().max().unwrap_or(0);
+        let total: u128 = self
+            .items
+            .iter()
+            .filter(|item| item.get_weight() <= max_capacity)
+            .map(|item| item.get_value() as u128)
+            .sum();
+        if total > u64::MAX as u128 {
+            return Err(KnapsackError::ArithmeticOverflow);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a solution of the multiple knapsack problem.
+///
+/// This structure contains the knapsack every item is assigned to, the loads of the knapsacks
+/// and the total value of the assigned items, and the identifiers of the items.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct MultipleKnapsackSolution {
+    assignments: Vec<Option<usize>>,  // The knapsack of every item, or `None` if the item is not taken
+    #[serde(default)]
+    item_ids: Vec<Option<u64>>,       // Identifiers of the items, in the order of `assignments`
+    loads: Vec<u64>,                  // The total weight of the items in every knapsack
+    total_value: u64,                 // The total value of the assigned items
+}
+
+impl MultipleKnapsackSolution {
+    /// Creates a new `MultipleKnapsackSolution` from the assignments of the items.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `MultipleKnapsack` the items were assigned from.
+    /// * `assignments` - The index of the knapsack of every item, or `None` if the item is not taken.
+    ///
+    /// # Returns
+    ///
+    /// A new `MultipleKnapsackSolution` instance with the specified assignments.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the number of assignments differs from the number of items
+    /// or if any knapsack index is out of bounds.
+    pub fn new(knapsack: &MultipleKnapsack, assignments: Vec<Option<usize>>) -> Self {
+        assert_eq!(assignments.len(), knapsack.get_items_len());
+        let mut loads = vec![0; knapsack.get_knapsacks_len()];
+        let mut total_value = 0;
+
+        for (i, assignment) in assignments.iter().enumerate() {
+            if let Some(k) = *assignment {
+                loads[k] += knapsack.get_item(i).get_weight();
+                total_value += knapsack.get_item(i).get_value();
+            }
+        }
+        let item_ids = (0..assignments.len()).map(|i| knapsack.get_item_id(i)).collect();
+
+        Self {
+            assignments,
+            item_ids,
+            loads,
+            total_value,
+        }
+    }
+
+    /// Gets the knapsack every item is assigned to.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the index of the knapsack of every item, or `None` if the item is not taken.
+    pub fn get_assignments(&self) -> &[Option<usize>] {
+        &self.assignments
+    }
+
+    /// Gets the identifiers of the items (see `MultipleKnapsack::get_item_id`).
+    ///
+    /// # Returns
+    ///
+    /// A slice of identifiers in the same order as the assignments of `get_assignments`, with `None` for the items without an identifier.
+    pub fn get_item_ids(&self) -> &[Option<u64>] {
+        &self.item_ids
+    }
+
+    /// Gets the total weight of the items in every knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the load of every knapsack.
+    pub fn get_loads(&self) -> &[u64] {
+        &self.loads
+    }
+
+    /// Gets the total value of the assigned items.
+    ///
+    /// # Returns
+    ///
+    /// The total value as a `u64`.
+    pub fn get_total_value(&self) -> u64 {
+        self.total_value
+    }
+}
@@ -0,0 +1,38 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solution::MultipleKnapsackSolution;
+
+/// A trait for solving the multiple knapsack problem.
+///
+/// All multiple knapsack-solving algorithms should implement this trait. It mirrors
+/// `KnapsackSolver` for several knapsacks filled from one list of items.
+pub trait MultipleKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the name of the algorithm (e.g., "Multiple branch and bound").
+    fn get_name(&self) -> String;
+
+    /// Solves the multiple knapsack problem for the given `MultipleKnapsack`.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `MultipleKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// The maximum total value of the items assigned to the knapsacks.
+    fn solve(&self, knapsack: &MultipleKnapsack) -> Result<u64, KnapsackError>;
+
+    /// Solves the multiple knapsack problem and returns the knapsack of every item.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `MultipleKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// A `MultipleKnapsackSolution` with the assignments of the items.
+    fn solve_with_items(&self, knapsack: &MultipleKnapsack) -> Result<MultipleKnapsackSolution, KnapsackError>;
+}
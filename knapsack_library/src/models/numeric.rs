@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+/// A trait for the numeric types of weights and values of the generic knapsack.
+///
+/// It is implemented for the unsigned integers `u32`, `u64` and `u128` and for the floating-point
+/// types `f32` and `f64`. Solvers which only compare and add up numbers, like greedy and branch and bound,
+/// accept any `Numeric` type.
+pub trait Numeric: Copy + Debug + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    /// Returns the zero of the type.
+    fn zero() -> Self;
+
+    /// Checks whether the number may be a weight or a value: it must be non-negative and finite.
+    fn is_valid(self) -> bool;
+
+    /// Adds two numbers, returning `None` if the sum doesn't fit in the type (or isn't finite).
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Compares the value-to-weight ratios of two `(weight, value)` pairs with non-zero weights.
+    fn compare_ratios(a: (Self, Self), b: (Self, Self)) -> Ordering;
+
+    /// Returns an upper bound of `value * part / weight` for `part < weight`,
+    /// i.e. the value of the part of an item which fits in the knapsack.
+    fn fractional_value(value: Self, weight: Self, part: Self) -> Self;
+}
+
+/// A trait for the integer types of weights and values of the generic knapsack.
+///
+/// Only these types can index a dynamic programming table, so the dynamic programming solvers
+/// require it and can't be used with floating-point data.
+pub trait Integer: Numeric + Ord {
+    /// Converts the number to a `usize`, returning `None` if it doesn't fit.
+    fn to_usize(self) -> Option<usize>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn is_valid(self) -> bool {
+                true
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            // Compares the continued fractions of the ratios, so that nothing is multiplied and can't overflow
+            fn compare_ratios(a: (Self, Self), b: (Self, Self)) -> Ordering {
+                let (mut a_num, mut a_den) = (a.1, a.0);
+                let (mut b_num, mut b_den) = (b.1, b.0);
+                let mut reversed = false;
+                loop {
+                    let order = (a_num / a_den).cmp(&(b_num / b_den));
+                    let (a_rem, b_rem) = (a_num % a_den, b_num % b_den);
+                    let order = match order {
+                        Ordering::Equal if a_rem == 0 || b_rem == 0 => a_rem.cmp(&b_rem),
+                        Ordering::Equal => {
+                            // Compare the reciprocals of the remainders, which reverses the order
+                            (a_num, a_den, b_num, b_den) = (a_den, a_rem, b_den, b_rem);
+                            reversed = !reversed;
+                            continue;
+                        }
+                        order => order,
+                    };
+                    return if reversed { order.reverse() } else { order };
+                }
+            }
+
+            // The optimum is an integer, so the bound may be rounded down; it's the whole value on an overflow
+            fn fractional_value(value: Self, weight: Self, part: Self) -> Self {
+                value.checked_mul(part).map_or(value, |product| product / weight)
+            }
+        }
+
+        impl Integer for $t {
+            fn to_usize(self) -> Option<usize> {
+                usize::try_from(self).ok()
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn is_valid(self) -> bool {
+                self.is_finite() && self >= 0.0
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                let sum = self + other;
+                sum.is_finite().then_some(sum)
+            }
+
+            fn compare_ratios(a: (Self, Self), b: (Self, Self)) -> Ordering {
+                (a.1 / a.0).total_cmp(&(b.1 / b.0))
+            }
+
+            fn fractional_value(value: Self, weight: Self, part: Self) -> Self {
+                value * (part / weight)
+            }
+        }
+    )*};
+}
+
+impl_integer!(u32, u64, u128);
+impl_float!(f32, f64);
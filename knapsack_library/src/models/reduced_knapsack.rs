@@ -0,0 +1,93 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solution::KnapsackSolution;
+
+/// A structure representing a knapsack reduced by preprocessing, with the mapping back to the original knapsack.
+///
+/// The reduced knapsack only contains the items whose selection is still undecided, with their weights
+/// divided by the common divisor. The items forced into every optimal solution are kept separately,
+/// and their weight is already subtracted from the capacity.
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub struct ReducedKnapsack {
+    knapsack: Knapsack,           // The reduced knapsack
+    original_indices: Vec<usize>, // The index in the original knapsack of every item of the reduced one
+    fixed_items: Vec<usize>,      // Indices of the items of the original knapsack which are always taken
+    fixed_value: u64,             // The total value of the fixed items
+    weight_divisor: u64,          // The common divisor the weights and the capacity were divided by
+}
+
+impl ReducedKnapsack {
+    /// Creates a new `ReducedKnapsack`.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - The reduced knapsack.
+    /// * `original_indices` - The index in the original knapsack of every item of the reduced one.
+    /// * `fixed_items` - Indices of the items of the original knapsack which are always taken.
+    /// * `fixed_value` - The total value of the fixed items.
+    /// * `weight_divisor` - The common divisor the weights and the capacity were divided by.
+    ///
+    /// # Returns
+    ///
+    /// A new `ReducedKnapsack` instance.
+    pub fn new(
+        knapsack: Knapsack,
+        original_indices: Vec<usize>,
+        fixed_items: Vec<usize>,
+        fixed_value: u64,
+        weight_divisor: u64,
+    ) -> Self {
+        Self {
+            knapsack,
+            original_indices,
+            fixed_items,
+            fixed_value,
+            weight_divisor,
+        }
+    }
+
+    /// Gets the reduced knapsack.
+    pub fn get_knapsack(&self) -> &Knapsack {
+        &self.knapsack
+    }
+
+    /// Gets the index in the original knapsack of every item of the reduced one.
+    pub fn get_original_indices(&self) -> &[usize] {
+        &self.original_indices
+    }
+
+    /// Gets the indices of the items of the original knapsack which are always taken.
+    pub fn get_fixed_items(&self) -> &[usize] {
+        &self.fixed_items
+    }
+
+    /// Gets the total value of the items which are always taken.
+    pub fn get_fixed_value(&self) -> u64 {
+        self.fixed_value
+    }
+
+    /// Gets the common divisor the weights and the capacity were divided by.
+    pub fn get_weight_divisor(&self) -> u64 {
+        self.weight_divisor
+    }
+
+    /// Maps a solution of the reduced knapsack back to the original knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - A reference to the original `Knapsack` this one was reduced from.
+    /// * `solution` - A solution of the reduced knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A `KnapsackSolution` of the original knapsack with the fixed items and the mapped selected items.
+    pub fn restore(&self, original: &Knapsack, solution: &KnapsackSolution) -> KnapsackSolution {
+        let items = solution
+            .get_items()
+            .iter()
+            .map(|&i| self.original_indices[i])
+            .chain(self.fixed_items.iter().copied())
+            .collect();
+        KnapsackSolution::new(original, items)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::models::solve_observer::{SolveEvent, SolveObserver};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A flag shared between the caller and a running solver to stop it.
+///
+/// Clones of the token share the flag, so the caller can keep one clone and cancel the solver
+/// from another thread while it runs with the other.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>, // Whether the cancellation was requested
+}
+
+impl CancellationToken {
+    /// Creates a new token which isn't cancelled.
+    ///
+    /// # Returns
+    ///
+    /// A new `CancellationToken` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the solvers using this token to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks whether the cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A structure describing when a solver must stop (a deadline and/or a cancellation token)
+/// and who receives its progress (an observer).
+///
+/// Solvers check the context periodically, so they stop shortly after the deadline or the cancellation.
+/// A context without a deadline and a token never stops the solver.
+#[derive(Clone, Default)]
+pub struct SolveContext {
+    deadline: Option<Instant>,                     // The moment after which the solver must stop
+    cancellation_token: Option<CancellationToken>, // The token which stops the solver when cancelled
+    observer: Option<Arc<dyn SolveObserver>>,      // The receiver of the progress events
+}
+
+impl SolveContext {
+    /// Creates a context which never stops the solver.
+    ///
+    /// # Returns
+    ///
+    /// A new `SolveContext` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the moment after which the solver must stop.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the time the solver may run for, counting from now.
+    pub fn with_time_limit(self, time_limit: Duration) -> Self {
+        self.with_deadline(Instant::now() + time_limit)
+    }
+
+    /// Sets the token which stops the solver when cancelled.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Sets the observer receiving the progress events of the solver.
+    pub fn with_observer(mut self, observer: Arc<dyn SolveObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Gets the moment after which the solver must stop, if any.
+    pub fn get_deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Checks whether the solver must stop, because the deadline has passed or the token was cancelled.
+    pub fn is_stopped(&self) -> bool {
+        self.cancellation_token.as_ref().is_some_and(|token| token.is_cancelled())
+            || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Reports a progress event to the observer, if one is attached.
+    pub fn notify(&self, event: SolveEvent) {
+        if let Some(observer) = &self.observer {
+            observer.on_event(event);
+        }
+    }
+}
+
+impl fmt::Debug for SolveContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SolveContext")
+            .field("deadline", &self.deadline)
+            .field("cancellation_token", &self.cancellation_token)
+            .field("observer", &self.observer.is_some())
+            .finish()
+    }
+}
+
+/// A helper for solvers which checks the context once in a number of steps, since reading the clock is slow.
+///
+/// Every check also reports the number of steps done so far to the observer as explored nodes.
+/// Once the context is found stopped, every further check reports it without looking at the context again.
+pub(crate) struct StopCheck<'a> {
+    context: &'a SolveContext, // The context being checked
+    steps: u32,                // The number of steps since the last check
+    nodes: u64,                // The number of steps before the last check
+    stopped: bool,             // Whether the context was found stopped
+}
+
+impl<'a> StopCheck<'a> {
+    /// The number of steps between two checks of the context.
+    const INTERVAL: u32 = 1 << 12;
+
+    /// Creates a helper checking the given context.
+    pub(crate) fn new(context: &'a SolveContext) -> Self {
+        Self { context, steps: 0, nodes: 0, stopped: context.is_stopped() }
+    }
+
+    /// Counts a step and checks whether the solver must stop.
+    pub(crate) fn should_stop(&mut self) -> bool {
+        if !self.stopped {
+            self.steps += 1;
+            if self.steps == Self::INTERVAL {
+                self.steps = 0;
+                self.nodes += Self::INTERVAL as u64;
+                self.context.notify(SolveEvent::NodesExplored { nodes: self.nodes });
+                self.stopped = self.context.is_stopped();
+            }
+        }
+        self.stopped
+    }
+
+    /// Reports a progress event to the observer of the context.
+    pub(crate) fn notify(&self, event: SolveEvent) {
+        self.context.notify(event);
+    }
+
+    /// Checks whether the solver was stopped, without counting a step.
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+}
@@ -0,0 +1,33 @@
+/// A progress event reported by a solver to its `SolveObserver`.
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq)]
+pub enum SolveEvent {
+    /// A solution better than all previous ones was found; `value` is its total value.
+    IncumbentFound { value: u64 },
+    /// The dynamic programming table was filled for `row` of the `rows` items.
+    RowCompleted { row: usize, rows: usize },
+    /// The solver visited `nodes` nodes (combinations or states) so far.
+    NodesExplored { nodes: u64 },
+}
+
+/// A trait for receiving the progress of a running solver.
+///
+/// The observer is attached to a `SolveContext`, and the solvers call it from the thread they run on,
+/// so it should return quickly. Any `Fn(SolveEvent)` closure is an observer.
+pub trait SolveObserver: Send + Sync {
+    /// Receives a progress event of the solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The reported `SolveEvent`.
+    fn on_event(&self, event: SolveEvent);
+}
+
+impl<F> SolveObserver for F
+where
+    F: Fn(SolveEvent) + Send + Sync,
+{
+    fn on_event(&self, event: SolveEvent) {
+        self(event)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::models::knapsack_solution::KnapsackSolution;
+
+/// A structure representing the result of a solver which may have been stopped by a `SolveContext`.
+///
+/// If the solver was stopped, the solution is the best one found so far, and it isn't proven
+/// to be as good as the solver guarantees (optimal for exact solvers).
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub struct SolveOutcome {
+    solution: KnapsackSolution, // The best solution found
+    proven: bool,               // Whether the solver finished its search
+}
+
+impl SolveOutcome {
+    /// Creates a new `SolveOutcome`.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The best solution found.
+    /// * `proven` - Whether the solver finished its search.
+    ///
+    /// # Returns
+    ///
+    /// A new `SolveOutcome` instance.
+    pub fn new(solution: KnapsackSolution, proven: bool) -> Self {
+        Self { solution, proven }
+    }
+
+    /// Gets the best solution found.
+    pub fn get_solution(&self) -> &KnapsackSolution {
+        &self.solution
+    }
+
+    /// Checks whether the solver finished its search, so the solution is as good as the solver guarantees.
+    pub fn is_proven(&self) -> bool {
+        self.proven
+    }
+
+    /// Takes the best solution found.
+    pub fn into_solution(self) -> KnapsackSolution {
+        self.solution
+    }
+}
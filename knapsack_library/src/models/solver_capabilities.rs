@@ -0,0 +1,227 @@
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+
+/// The largest number of cells a dynamic programming table may have to be considered supported.
+///
+/// Tables indexed by capacity or by value hold 8 bytes per cell, so this is about 8 GiB.
+pub const MAX_TABLE_CELLS: u64 = 1 << 30;
+
+/// Checks that the bitsets of decisions of a dynamic programming fit in `MAX_TABLE_CELLS` words.
+///
+/// The solvers which reconstruct the selected items keep a bitset per item, so the limit
+/// on the capacity alone doesn't bound their memory.
+///
+/// # Arguments
+///
+/// * `rows` - The number of bitsets, usually one per item.
+/// * `words` - The number of words of every bitset.
+///
+/// # Returns
+///
+/// `Ok(())` if the bitsets fit, or `KnapsackError::CapacityTooLarge` otherwise.
+pub(crate) fn check_decisions_size(rows: usize, words: usize) -> Result<(), KnapsackError> {
+    if rows as u128 * words as u128 > MAX_TABLE_CELLS as u128 {
+        return Err(KnapsackError::CapacityTooLarge);
+    }
+    Ok(())
+}
+
+/// How close the results of a solver are to the optimum.
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq)]
+pub enum Exactness {
+    /// The solver always finds the optimal value.
+    Exact,
+    /// The solver guarantees at least the given fraction of the optimal value.
+    Approximate(f64),
+    /// The solver gives no guarantee on the value.
+    Heuristic,
+}
+
+/// Metadata describing what a knapsack solver can do.
+///
+/// The asymptotic classes are informal strings, e.g. "O(nW)", where `n` is the number of items,
+/// `W` is the capacity and `P` is the sum of the values.
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub struct SolverCapabilities {
+    exactness: Exactness,            // How close the results are to the optimum
+    time_complexity: String,         // The asymptotic time class
+    space_complexity: String,        // The asymptotic space class
+    returns_items: bool,             // Whether `solve_with_items` returns the selected items
+    max_items: Option<usize>,        // The largest supported number of items
+    max_capacity: Option<u64>,       // The largest supported capacity
+    max_profit_sum: Option<u128>,    // The largest supported sum of the values of the items
+    proportional_values: bool,       // Whether the values of the items must be proportional to their weights
+}
+
+impl SolverCapabilities {
+    /// Creates capabilities without size limits for a solver that returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `exactness` - How close the results of the solver are to the optimum.
+    /// * `time_complexity` - The asymptotic time class, e.g. "O(nW)".
+    /// * `space_complexity` - The asymptotic space class, e.g. "O(W)".
+    ///
+    /// # Returns
+    ///
+    /// A new `SolverCapabilities` instance.
+    pub fn new(exactness: Exactness, time_complexity: &str, space_complexity: &str) -> Self {
+        Self {
+            exactness,
+            time_complexity: time_complexity.to_string(),
+            space_complexity: space_complexity.to_string(),
+            returns_items: true,
+            max_items: None,
+            max_capacity: None,
+            max_profit_sum: None,
+            proportional_values: false,
+        }
+    }
+
+    /// Creates the capabilities assumed for solvers which don't describe themselves.
+    ///
+    /// Nothing is promised: the solver is considered a heuristic without item selections.
+    ///
+    /// # Returns
+    ///
+    /// A new `SolverCapabilities` instance with unknown complexity.
+    pub fn unknown() -> Self {
+        let mut capabilities = Self::new(Exactness::Heuristic, "unknown", "unknown");
+        capabilities.returns_items = false;
+        capabilities
+    }
+
+    /// Limits the number of items.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_items` - The largest supported number of items.
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Limits the capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_capacity` - The largest supported capacity.
+    pub fn with_max_capacity(mut self, max_capacity: u64) -> Self {
+        self.max_capacity = Some(max_capacity);
+        self
+    }
+
+    /// Limits the sum of the values of the items.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_profit_sum` - The largest supported sum of the values.
+    pub fn with_max_profit_sum(mut self, max_profit_sum: u128) -> Self {
+        self.max_profit_sum = Some(max_profit_sum);
+        self
+    }
+
+    /// Restricts the solver to subset-sum instances, whose values are proportional to the weights
+    /// (see `Knapsack::has_proportional_values`).
+    pub fn with_proportional_values(mut self) -> Self {
+        self.proportional_values = true;
+        self
+    }
+
+    /// Gets how close the results of the solver are to the optimum.
+    pub fn get_exactness(&self) -> Exactness {
+        self.exactness
+    }
+
+    /// Checks whether the solver always finds the optimal value.
+    pub fn is_exact(&self) -> bool {
+        self.exactness == Exactness::Exact
+    }
+
+    /// Gets the guaranteed fraction of the optimal value.
+    ///
+    /// # Returns
+    ///
+    /// `Some(1.0)` for exact solvers, the ratio for approximate ones and `None` for heuristics.
+    pub fn get_approximation_ratio(&self) -> Option<f64> {
+        match self.exactness {
+            Exactness::Exact => Some(1.0),
+            Exactness::Approximate(ratio) => Some(ratio),
+            Exactness::Heuristic => None,
+        }
+    }
+
+    /// Gets the asymptotic time class of the solver.
+    pub fn get_time_complexity(&self) -> &str {
+        &self.time_complexity
+    }
+
+    /// Gets the asymptotic space class of the solver.
+    pub fn get_space_complexity(&self) -> &str {
+        &self.space_complexity
+    }
+
+    /// Checks whether `solve_with_items` returns the selected items.
+    pub fn returns_items(&self) -> bool {
+        self.returns_items
+    }
+
+    /// Gets the largest supported number of items, if limited.
+    pub fn get_max_items(&self) -> Option<usize> {
+        self.max_items
+    }
+
+    /// Gets the largest supported capacity, if limited.
+    pub fn get_max_capacity(&self) -> Option<u64> {
+        self.max_capacity
+    }
+
+    /// Gets the largest supported sum of the values of the items, if limited.
+    pub fn get_max_profit_sum(&self) -> Option<u128> {
+        self.max_profit_sum
+    }
+
+    /// Checks whether the solver only supports knapsacks with values proportional to the weights.
+    pub fn requires_proportional_values(&self) -> bool {
+        self.proportional_values
+    }
+
+    /// Checks whether the solver can handle the knapsack within its size limits and requirements.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `Knapsack` to check.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the knapsack is supported, or the error describing the exceeded limit.
+    /// A knapsack breaking the proportional values requirement gives `KnapsackError::InvalidInstance`.
+    pub fn check(&self, knapsack: &Knapsack) -> Result<(), KnapsackError> {
+        if let Some(max) = self.max_items {
+            if knapsack.get_items_len() > max {
+                return Err(KnapsackError::TooManyItems { items: knapsack.get_items_len(), max });
+            }
+        }
+        if self.max_capacity.is_some_and(|max| knapsack.get_capacity() > max) {
+            return Err(KnapsackError::CapacityTooLarge);
+        }
+        if let Some(max) = self.max_profit_sum {
+            // Items which don't fit alone never reach the table
+            let profit_sum = (0..knapsack.get_items_len())
+                .map(|i| knapsack.get_item(i))
+                .filter(|item| item.get_weight() <= knapsack.get_capacity())
+                .fold(0u128, |sum, item| sum.saturating_add(item.get_value() as u128 * item.get_count() as u128));
+            if profit_sum > max {
+                return Err(KnapsackError::CapacityTooLarge);
+            }
+        }
+        if self.proportional_values && !knapsack.has_proportional_values() {
+            return Err(KnapsackError::InvalidInstance(
+                "the values are not proportional to the weights".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::models::item::Item;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing an unbounded knapsack for the algorithms.
+///
+/// This structure contains the capacity of the knapsack and a list of item types. Every item type
+/// can be placed in the knapsack any number of times. When `exact_fill` is set, only combinations
+/// whose total weight is exactly equal to the capacity are allowed (coin-change style questions).
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct UnboundedKnapsack {
+    capacity: u64,          // The capacity of the knapsack (maximum weight it can hold)
+    items: Vec<Item>,       // A list of item types that can be placed in the knapsack
+    #[serde(default)]
+    exact_fill: bool,       // Whether the total weight must be exactly equal to the capacity
+}
+
+impl UnboundedKnapsack {
+    /// Creates a new `UnboundedKnapsack` with the given capacity and list of item types.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The capacity of the knapsack, i.e., the maximum weight it can carry.
+    /// * `items` - A `Vec<Item>` representing the item types that can be placed in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnboundedKnapsack` instance with the specified capacity and items.
+    pub fn new(capacity: u64, items: Vec<Item>) -> Self {
+        Self {
+            capacity,
+            items,
+            exact_fill: false,
+        }
+    }
+
+    /// Creates a new `UnboundedKnapsack` that must be filled exactly up to its capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The capacity of the knapsack, i.e., the exact weight of the selected items.
+    /// * `items` - A `Vec<Item>` representing the item types that can be placed in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnboundedKnapsack` instance with the specified capacity and items.
+    pub fn new_exact_fill(capacity: u64, items: Vec<Item>) -> Self {
+        Self {
+            capacity,
+            items,
+            exact_fill: true,
+        }
+    }
+
+    /// Gets the number of item types in the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The number of item types as a `usize`.
+    pub fn get_items_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Gets a reference to the item type at the specified index in the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item type to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `Item` at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item(&self, index: usize) -> &Item {
+        &self.items[index]
+    }
+
+    /// Gets the stable identifier of the item at the specified index (see `Knapsack::get_item_id`).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    ///
+    /// The identifier set with `Item::with_id`, or `None` if none was set.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the `index` is out of bounds.
+    pub fn get_item_id(&self, index: usize) -> Option<u64> {
+        self.items[index].get_id()
+    }
+
+    /// Gets the capacity of the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// The capacity of the knapsack as a `u64`.
+    pub fn get_capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// Checks whether the knapsack must be filled exactly up to its capacity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if only combinations with the total weight equal to the capacity are allowed.
+    pub fn is_exact_fill(&self) -> bool {
+        self.exact_fill
+    }
+}
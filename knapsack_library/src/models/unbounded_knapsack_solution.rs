@@ -0,0 +1,93 @@
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use serde::{Serialize, Deserialize};
+
+/// A structure representing a solution of the unbounded knapsack problem.
+///
+/// This structure contains how many copies of every item type are selected along with their
+/// total weight and value, and the identifiers of the item types.
+#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
+#[derive(PartialEq)]
+pub struct UnboundedKnapsackSolution {
+    counts: Vec<u64>,   // The number of selected copies of every item type
+    #[serde(default)]
+    item_ids: Vec<Option<u64>>, // Identifiers of the item types, in the order of `counts`
+    total_weight: u64,  // The total weight of the selected items
+    total_value: u64,   // The total value of the selected items
+}
+
+impl UnboundedKnapsackSolution {
+    /// Creates a new `UnboundedKnapsackSolution` from the selected copies of item types.
+    ///
+    /// The total weight and value are computed from the items of the knapsack.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `UnboundedKnapsack` the items were selected from.
+    /// * `counts` - The number of selected copies of every item type of the knapsack.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnboundedKnapsackSolution` instance with the specified counts.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the length of `counts` differs from the number of item types.
+    pub fn new(knapsack: &UnboundedKnapsack, counts: Vec<u64>) -> Self {
+        assert_eq!(counts.len(), knapsack.get_items_len());
+        let total_weight = counts
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| count * knapsack.get_item(i).get_weight())
+            .sum();
+        let total_value = counts
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| count * knapsack.get_item(i).get_value())
+            .sum();
+        let item_ids = (0..counts.len()).map(|i| knapsack.get_item_id(i)).collect();
+
+        Self {
+            counts,
+            item_ids,
+            total_weight,
+            total_value,
+        }
+    }
+
+    /// Gets the number of selected copies of every item type.
+    ///
+    /// # Returns
+    ///
+    /// A slice with a count per item type of the knapsack.
+    pub fn get_counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Gets the identifiers of the item types (see `UnboundedKnapsack::get_item_id`).
+    ///
+    /// # Returns
+    ///
+    /// A slice of identifiers in the same order as the counts of `get_counts`, with `None` for the items without an identifier.
+    pub fn get_item_ids(&self) -> &[Option<u64>] {
+        &self.item_ids
+    }
+
+    /// Gets the total weight of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// The total weight as a `u64`.
+    pub fn get_total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// Gets the total value of the selected items.
+    ///
+    /// # Returns
+    ///
+    /// The total value as a `u64`.
+    pub fn get_total_value(&self) -> u64 {
+        self.total_value
+    }
+}
@@ -0,0 +1,39 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::models::unbounded_knapsack_solution::UnboundedKnapsackSolution;
+
+/// A trait for solving the unbounded knapsack problem.
+///
+/// All unbounded knapsack-solving algorithms should implement this trait. It mirrors
+/// `KnapsackSolver` for instances where every item type can be taken any number of times.
+pub trait UnboundedKnapsackSolver {
+    /// Returns the name of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// A string representing the name of the algorithm (e.g., "Unbounded dynamic").
+    fn get_name(&self) -> String;
+
+    /// Solves the unbounded knapsack problem for the given `UnboundedKnapsack`.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `UnboundedKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// The maximum value that can be achieved by selecting copies of item types.
+    /// For exact-fill knapsacks an error is returned if the capacity can't be filled exactly.
+    fn solve(&self, knapsack: &UnboundedKnapsack) -> Result<u64, KnapsackError>;
+
+    /// Solves the unbounded knapsack problem and returns the selected copies of item types.
+    ///
+    /// # Arguments
+    ///
+    /// * `knapsack` - A reference to the `UnboundedKnapsack` instance to solve the problem for.
+    ///
+    /// # Returns
+    ///
+    /// An `UnboundedKnapsackSolution` with the number of copies of every item type.
+    fn solve_with_items(&self, knapsack: &UnboundedKnapsack) -> Result<UnboundedKnapsackSolution, KnapsackError>;
+}
@@ -0,0 +1,117 @@
+use crate::algorithms_impls::multi_dimensional_branch_and_bound::MultiDimensionalBranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::multi_dimensional_dynamic::MultiDimensionalDynamicKnapsackSolver;
+use crate::algorithms_impls::multi_dimensional_greedy::MultiDimensionalGreedyKnapsackSolver;
+use crate::algorithms_registry::resolve_name;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::name_to_id;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solution::MultiDimensionalKnapsackSolution;
+use crate::models::multi_dimensional_knapsack_solver::MultiDimensionalKnapsackSolver;
+
+/// A service that manages multi-dimensional knapsack solving algorithms.
+///
+/// This service mirrors `AlgorithmsService` for knapsacks with several capacity constraints.
+pub struct MultiDimensionalAlgorithmsService;
+
+impl MultiDimensionalAlgorithmsService {
+    /// Lowercase aliases of the algorithms with the identifiers they refer to.
+    const ALIASES: [(&'static str, &'static str); 2] = [
+        ("dp", "multi_dimensional_dynamic"),
+        ("bnb", "multi_dimensional_branch_and_bound"),
+    ];
+
+    /// Returns a list of all available multi-dimensional knapsack-solving algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn MultiDimensionalKnapsackSolver>>` containing boxed instances of all available algorithms.
+    pub fn get_all_algorithms() -> Vec<Box<dyn MultiDimensionalKnapsackSolver>> {
+        vec![
+            Box::new(MultiDimensionalBranchAndBoundKnapsackSolver),
+            Box::new(MultiDimensionalGreedyKnapsackSolver),
+            Box::new(MultiDimensionalDynamicKnapsackSolver),
+        ]
+    }
+
+    /// Returns the multi-dimensional knapsack-solving algorithm with the given identifier, name or alias.
+    ///
+    /// The name is matched ignoring case, like in `AlgorithmsService`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier (e.g. "multi_dimensional_dynamic"), name or alias (e.g. "dp") of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The algorithm, or `KnapsackError::UnknownAlgorithm` listing the valid names.
+    pub fn get_algorithm(name: &str) -> Result<Box<dyn MultiDimensionalKnapsackSolver>, KnapsackError> {
+        let mut algorithms = MultiDimensionalAlgorithmsService::get_all_algorithms();
+        let ids_and_names: Vec<(String, String)> = algorithms
+            .iter()
+            .map(|algorithm| (name_to_id(&algorithm.get_name()), algorithm.get_name()))
+            .collect();
+        let position = resolve_name(name, &ids_and_names, &MultiDimensionalAlgorithmsService::ALIASES)?;
+        Ok(algorithms.swap_remove(position))
+    }
+
+    /// Returns a list of multi-dimensional knapsack-solving algorithms which matches string names.
+    ///
+    /// Every name may be an identifier, a display name or an alias, and is matched ignoring case.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn MultiDimensionalKnapsackSolver>>` in the order of the names, without duplicates,
+    /// or `KnapsackError::UnknownAlgorithm` listing the valid names if any of the names is unknown.
+    pub fn get_algorithms_by_names(algorithm_names: Vec<String>) -> Result<Vec<Box<dyn MultiDimensionalKnapsackSolver>>, KnapsackError> {
+        let mut algorithms: Vec<Box<dyn MultiDimensionalKnapsackSolver>> = Vec::with_capacity(algorithm_names.len());
+        for name in &algorithm_names {
+            let algorithm = MultiDimensionalAlgorithmsService::get_algorithm(name)?;
+            if !algorithms.iter().any(|other| other.get_name() == algorithm.get_name()) {
+                algorithms.push(algorithm);
+            }
+        }
+        Ok(algorithms)
+    }
+
+    /// Solves the multi-dimensional knapsack problem using the specified algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `MultiDimensionalKnapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<u64, KnapsackError>` with the maximum value, or an error if the algorithm
+    /// with the given name is not found or fails.
+    pub fn solve(name: String, knapsack: &MultiDimensionalKnapsack) -> Result<u64, KnapsackError> {
+        MultiDimensionalAlgorithmsService::get_algorithm(&name)?.solve(knapsack)
+    }
+
+    /// Solves the multi-dimensional knapsack problem using the specified algorithm and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `MultiDimensionalKnapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<MultiDimensionalKnapsackSolution, KnapsackError>` with the selected items, or an error if
+    /// the algorithm with the given name is not found or fails.
+    pub fn solve_with_items(name: String, knapsack: &MultiDimensionalKnapsack) -> Result<MultiDimensionalKnapsackSolution, KnapsackError> {
+        MultiDimensionalAlgorithmsService::get_algorithm(&name)?.solve_with_items(knapsack)
+    }
+
+    /// Returns the names of all available multi-dimensional algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` containing the names of all available algorithms.
+    pub fn get_algorithms_names() -> Vec<String> {
+        MultiDimensionalAlgorithmsService::get_all_algorithms()
+            .into_iter()
+            .map(|algorithm| algorithm.get_name())
+            .collect()
+    }
+}
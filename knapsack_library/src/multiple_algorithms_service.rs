@@ -0,0 +1,117 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::greedy::GreedyKnapsackSolver;
+use crate::algorithms_impls::multiple_branch_and_bound::MultipleBranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::multiple_sequential::MultipleSequentialKnapsackSolver;
+use crate::algorithms_registry::resolve_name;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::name_to_id;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solution::MultipleKnapsackSolution;
+use crate::models::multiple_knapsack_solver::MultipleKnapsackSolver;
+
+/// A service that manages multiple knapsack solving algorithms.
+///
+/// This service mirrors `AlgorithmsService` for several knapsacks filled from one list of items.
+pub struct MultipleAlgorithmsService;
+
+impl MultipleAlgorithmsService {
+    /// Lowercase aliases of the algorithms with the identifiers they refer to.
+    const ALIASES: [(&'static str, &'static str); 1] = [
+        ("bnb", "multiple_branch_and_bound"),
+    ];
+
+    /// Returns a list of all available multiple knapsack-solving algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn MultipleKnapsackSolver>>` containing boxed instances of all available algorithms.
+    pub fn get_all_algorithms() -> Vec<Box<dyn MultipleKnapsackSolver>> {
+        vec![
+            Box::new(MultipleBranchAndBoundKnapsackSolver),
+            Box::new(MultipleSequentialKnapsackSolver::new(Box::new(BranchAndBoundKnapsackSolver))),
+            Box::new(MultipleSequentialKnapsackSolver::new(Box::new(GreedyKnapsackSolver))),
+        ]
+    }
+
+    /// Returns the multiple knapsack-solving algorithm with the given identifier, name or alias.
+    ///
+    /// The name is matched ignoring case, like in `AlgorithmsService`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier (e.g. "multiple_branch_and_bound"), name or alias (e.g. "bnb") of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The algorithm, or `KnapsackError::UnknownAlgorithm` listing the valid names.
+    pub fn get_algorithm(name: &str) -> Result<Box<dyn MultipleKnapsackSolver>, KnapsackError> {
+        let mut algorithms = MultipleAlgorithmsService::get_all_algorithms();
+        let ids_and_names: Vec<(String, String)> = algorithms
+            .iter()
+            .map(|algorithm| (name_to_id(&algorithm.get_name()), algorithm.get_name()))
+            .collect();
+        let position = resolve_name(name, &ids_and_names, &MultipleAlgorithmsService::ALIASES)?;
+        Ok(algorithms.swap_remove(position))
+    }
+
+    /// Returns a list of multiple knapsack-solving algorithms which matches string names.
+    ///
+    /// Every name may be an identifier, a display name or an alias, and is matched ignoring case.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn MultipleKnapsackSolver>>` in the order of the names, without duplicates,
+    /// or `KnapsackError::UnknownAlgorithm` listing the valid names if any of the names is unknown.
+    pub fn get_algorithms_by_names(algorithm_names: Vec<String>) -> Result<Vec<Box<dyn MultipleKnapsackSolver>>, KnapsackError> {
+        let mut algorithms: Vec<Box<dyn MultipleKnapsackSolver>> = Vec::with_capacity(algorithm_names.len());
+        for name in &algorithm_names {
+            let algorithm = MultipleAlgorithmsService::get_algorithm(name)?;
+            if !algorithms.iter().any(|other| other.get_name() == algorithm.get_name()) {
+                algorithms.push(algorithm);
+            }
+        }
+        Ok(algorithms)
+    }
+
+    /// Solves the multiple knapsack problem using the specified algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `MultipleKnapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<u64, KnapsackError>` with the maximum value, or an error if the algorithm
+    /// with the given name is not found or fails.
+    pub fn solve(name: String, knapsack: &MultipleKnapsack) -> Result<u64, KnapsackError> {
+        MultipleAlgorithmsService::get_algorithm(&name)?.solve(knapsack)
+    }
+
+    /// Solves the multiple knapsack problem using the specified algorithm and returns the knapsack of every item.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `MultipleKnapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<MultipleKnapsackSolution, KnapsackError>` with the assignments of the items, or an error if
+    /// the algorithm with the given name is not found or fails.
+    pub fn solve_with_items(name: String, knapsack: &MultipleKnapsack) -> Result<MultipleKnapsackSolution, KnapsackError> {
+        MultipleAlgorithmsService::get_algorithm(&name)?.solve_with_items(knapsack)
+    }
+
+    /// Returns the names of all available multiple algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` containing the names of all available algorithms.
+    pub fn get_algorithms_names() -> Vec<String> {
+        MultipleAlgorithmsService::get_all_algorithms()
+            .into_iter()
+            .map(|algorithm| algorithm.get_name())
+            .collect()
+    }
+}
@@ -0,0 +1,153 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_registry::AlgorithmsRegistry;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::{name_to_id, KnapsackSolver};
+use crate::models::solver_capabilities::SolverCapabilities;
+use crate::multi_dimensional_algorithms_service::MultiDimensionalAlgorithmsService;
+use crate::multiple_algorithms_service::MultipleAlgorithmsService;
+use crate::unbounded_algorithms_service::UnboundedAlgorithmsService;
+
+/// A downstream solver delegating to the dynamic programming, with a configurable name.
+///
+/// It is exact and has the capabilities of the dynamic programming, since other tests may run
+/// all algorithms of the default registry concurrently.
+struct RenamedSolver {
+    name: String,
+}
+
+impl KnapsackSolver for RenamedSolver {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_capabilities(&self) -> SolverCapabilities {
+        DynamicKnapsackSolver.get_capabilities()
+    }
+
+    fn solve(&self, knapsack: &Knapsack) -> Result<u64, KnapsackError> {
+        DynamicKnapsackSolver.solve(knapsack)
+    }
+
+    fn solve_with_items(&self, knapsack: &Knapsack) -> Result<KnapsackSolution, KnapsackError> {
+        DynamicKnapsackSolver.solve_with_items(knapsack)
+    }
+}
+
+#[test]
+// We check that the built-in registry matches the service.
+fn test_builtin_algorithms() {
+    let registry = AlgorithmsRegistry::with_builtin_algorithms();
+    for name in registry.get_algorithms_names() {
+        assert!(AlgorithmsService::get_algorithms_names().contains(&name), "{}", name);
+    }
+    assert!(AlgorithmsRegistry::empty().get_all_algorithms().is_empty());
+    assert_eq!(AlgorithmsRegistry::default().get_algorithms_ids(), AlgorithmsRegistry::with_builtin_algorithms().get_algorithms_ids());
+}
+
+#[test]
+// We check that registered algorithms can be found, replaced and removed.
+fn test_register_and_unregister() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10), Item::new(3, 7)]);
+    let mut registry = AlgorithmsRegistry::empty();
+
+    registry.register(|| Box::new(RenamedSolver { name: "Renamed".to_string() }));
+    registry.register(|| Box::new(DynamicKnapsackSolver));
+    assert_eq!(registry.get_algorithms_names(), vec!["Renamed".to_string(), "Dynamic".to_string()]);
+    assert_eq!(registry.solve("Renamed", &knapsack), Ok(17));
+    assert_eq!(registry.get_algorithms_by_names(&["Dynamic".to_string()]).unwrap().len(), 1);
+
+    // A solver with the same name replaces the registered one in place
+    registry.register(|| Box::new(RenamedSolver { name: "Dynamic".to_string() }));
+    assert_eq!(registry.get_algorithms_names(), vec!["Renamed".to_string(), "Dynamic".to_string()]);
+    assert_eq!(registry.get_all_algorithms().len(), 2);
+
+    assert!(registry.unregister("Renamed"));
+    assert!(!registry.unregister("Renamed"));
+    assert_eq!(
+        registry.solve_with_items("Renamed", &knapsack),
+        Err(KnapsackError::UnknownAlgorithm {
+            name: "Renamed".to_string(),
+            valid_names: vec!["Dynamic".to_string()],
+        })
+    );
+}
+
+#[test]
+// We check that an algorithm registered through the service is available to its functions.
+fn test_register_in_service() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10)]);
+    AlgorithmsService::register_algorithm(|| Box::new(RenamedSolver { name: "In-house dynamic".to_string() }));
+
+    assert!(AlgorithmsService::get_algorithms_names().contains(&"In-house dynamic".to_string()));
+    assert_eq!(AlgorithmsService::get_algorithms_by_names(vec!["In-house dynamic".to_string()]).unwrap().len(), 1);
+    assert_eq!(AlgorithmsService::solve("In-house dynamic".to_string(), &knapsack), Ok(10));
+
+    AlgorithmsRegistry::write_global().unregister("In-house dynamic");
+}
+
+#[test]
+// We check that algorithms are resolved by identifier, name or alias, ignoring case.
+fn test_name_resolution() {
+    let registry = AlgorithmsRegistry::with_builtin_algorithms();
+
+    for name in ["lazy_dynamic", "Lazy Dynamic", "Lazy dynamic", "LAZY_DYNAMIC"] {
+        assert_eq!(registry.get_algorithm(name).unwrap().get_id(), "lazy_dynamic", "{}", name);
+    }
+    assert_eq!(registry.get_algorithm("BnB").unwrap().get_name(), "Branch and bound");
+    assert_eq!(registry.get_algorithm("fptas_0_05").unwrap().get_name(), "FPTAS 0.05");
+
+    let names = ["dp".to_string(), "Greedy".to_string(), "dynamic".to_string()];
+    let algorithms = registry.get_algorithms_by_names(&names).unwrap();
+    let ids: Vec<String> = algorithms.iter().map(|algorithm| algorithm.get_id()).collect();
+    assert_eq!(ids, vec!["dynamic".to_string(), "greedy".to_string()]);
+
+    let names = ["Dynamic".to_string(), "Lazy dinamic".to_string()];
+    match registry.get_algorithms_by_names(&names) {
+        Err(KnapsackError::UnknownAlgorithm { name, valid_names }) => {
+            assert_eq!(name, "Lazy dinamic");
+            assert_eq!(valid_names, registry.get_algorithms_names());
+        }
+        _ => panic!("The unknown name must be reported"),
+    }
+}
+
+#[test]
+// We check that the services of the other knapsack problems resolve names the same way.
+fn test_other_services_name_resolution() {
+    for name in ["unbounded_dynamic", "Unbounded Dynamic", "DP"] {
+        assert_eq!(UnboundedAlgorithmsService::get_algorithm(name).unwrap().get_name(), "Unbounded dynamic", "{}", name);
+    }
+    for name in ["multiple_sequential_greedy", "MULTIPLE SEQUENTIAL (GREEDY)"] {
+        assert_eq!(MultipleAlgorithmsService::get_algorithm(name).unwrap().get_name(), "Multiple sequential (Greedy)", "{}", name);
+    }
+    assert_eq!(MultipleAlgorithmsService::get_algorithm("bnb").unwrap().get_name(), "Multiple branch and bound");
+    assert_eq!(MultiDimensionalAlgorithmsService::get_algorithm("Bnb").unwrap().get_name(), "Multi-dimensional branch and bound");
+
+    let names = vec!["dp".to_string(), "multi-dimensional greedy".to_string(), "multi_dimensional_dynamic".to_string()];
+    let algorithms = MultiDimensionalAlgorithmsService::get_algorithms_by_names(names).unwrap();
+    let names: Vec<String> = algorithms.iter().map(|algorithm| algorithm.get_name()).collect();
+    assert_eq!(names, vec!["Multi-dimensional dynamic".to_string(), "Multi-dimensional greedy".to_string()]);
+
+    match UnboundedAlgorithmsService::get_algorithms_by_names(vec!["dp".to_string(), "Dinamic".to_string()]) {
+        Err(KnapsackError::UnknownAlgorithm { name, valid_names }) => {
+            assert_eq!(name, "Dinamic");
+            assert_eq!(valid_names, UnboundedAlgorithmsService::get_algorithms_names());
+        }
+        _ => panic!("The unknown name must be reported"),
+    }
+}
+
+#[test]
+// We check that the identifiers are unique and derived names are well-formed.
+fn test_ids() {
+    let ids = AlgorithmsRegistry::with_builtin_algorithms().get_algorithms_ids();
+    for (i, id) in ids.iter().enumerate() {
+        assert!(!ids[..i].contains(id), "{}", id);
+    }
+    assert_eq!(name_to_id("FPTAS 0.5"), "fptas_0_5");
+    assert_eq!(name_to_id("  Multi-dimensional  greedy! "), "multi_dimensional_greedy");
+}
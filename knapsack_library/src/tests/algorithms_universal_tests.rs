@@ -1,6 +1,16 @@
 use crate::algorithms_service::AlgorithmsService;
 use crate::models::knapsack::Knapsack;
 use crate::models::item::Item;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+
+/// Returns the algorithms which support the knapsack, e.g. `Subset sum` only supports proportional values.
+fn supported_algorithms(knapsack: &Knapsack) -> Vec<Box<dyn KnapsackSolver>> {
+    AlgorithmsService::get_all_algorithms()
+        .into_iter()
+        .filter(|solver| solver.get_capabilities().check(knapsack).is_ok())
+        .collect()
+}
 
 #[test]
 // We check that the algorithm will take all the items if their total weight is equal to the weight of the backpack.
@@ -11,7 +21,7 @@ fn test_knapsack_all() {
     
     let knapsack = Knapsack::new(10, vec![item1, item2, item3]);
 
-    for solver in AlgorithmsService::get_all_algorithms() {
+    for solver in supported_algorithms(&knapsack) {
         assert_eq!(solver.solve(&knapsack), Ok(22));
     }
     
@@ -26,7 +36,7 @@ fn test_knapsack_one_odd() {
     
     let knapsack = Knapsack::new(10, vec![item1, item2, item3]);
 
-    for solver in AlgorithmsService::get_all_algorithms() {
+    for solver in supported_algorithms(&knapsack) {
         assert_eq!(solver.solve(&knapsack), Ok(17));
     }
 }
@@ -40,7 +50,7 @@ fn test_knapsack_elemets_too_big() {
     
     let knapsack = Knapsack::new(10, vec![item1, item2, item3]);
 
-    for solver in AlgorithmsService::get_all_algorithms() {
+    for solver in supported_algorithms(&knapsack) {
         assert_eq!(solver.solve(&knapsack), Ok(0));
     }
 }
@@ -50,7 +60,7 @@ fn test_knapsack_elemets_too_big() {
 fn test_knapsack_empty() {
     let knapsack = Knapsack::new(10, vec![]);
 
-    for solver in AlgorithmsService::get_all_algorithms() {
+    for solver in supported_algorithms(&knapsack) {
         assert_eq!(solver.solve(&knapsack), Ok(0));
     }
 }
@@ -66,7 +76,65 @@ fn test_knapsack_algo_based_on_value() {
 
     let knapsack = Knapsack::new(10, vec![item1, item2, item3, item4, item5]);
 
-    for solver in AlgorithmsService::get_all_algorithms() {
+    for solver in supported_algorithms(&knapsack) {
         assert_eq!(solver.solve(&knapsack), Ok(30));
     }
-}
\ No newline at end of file
+}
+
+#[test]
+// We check that the selected items fit in the knapsack and give the same value as `solve`.
+fn test_knapsack_selected_items() {
+    let item1 = Item::new(1, 2);
+    let item2 = Item::new(5, 15);
+    let item3 = Item::new(2, 4);
+    let item4 = Item::new(5, 15);
+    let item5 = Item::new(3, 8);
+
+    let knapsack = Knapsack::new(12, vec![item1, item2, item3, item4, item5]);
+
+    for solver in supported_algorithms(&knapsack) {
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        let weight: u64 = solution.get_items().iter().map(|&i| knapsack.get_item(i).get_weight()).sum();
+        let value: u64 = solution.get_items().iter().map(|&i| knapsack.get_item(i).get_value()).sum();
+
+        assert_eq!(Ok(solution.get_total_value()), solver.solve(&knapsack));
+        assert_eq!(solution.get_total_weight(), weight);
+        assert_eq!(solution.get_total_value(), value);
+        assert!(weight <= knapsack.get_capacity());
+    }
+}
+
+#[test]
+// We check that the service finds the algorithm by name when returning the selected items.
+fn test_service_solve_with_items() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10), Item::new(3, 7), Item::new(3, 5)]);
+
+    let solution = AlgorithmsService::solve_with_items("Dynamic".to_string(), &knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1]);
+    assert_eq!(solution.get_total_weight(), 8);
+    assert_eq!(solution.get_total_value(), 17);
+
+    assert!(matches!(
+        AlgorithmsService::solve_with_items("Unknown".to_string(), &knapsack),
+        Err(KnapsackError::UnknownAlgorithm { name, .. }) if name == "Unknown"
+    ));
+}
+
+#[test]
+// We check that the errors can be used as `std::error::Error` and keep readable messages.
+fn test_error_display() {
+    let error: Box<dyn std::error::Error> = Box::new(KnapsackError::CapacityTooLarge);
+    assert_eq!(error.to_string(), "Capacity too large to process");
+    assert_eq!(
+        KnapsackError::TooManyItems { items: 66, max: 64 }.to_string(),
+        "The number of items (66) exceeds the maximum allowed (64)"
+    );
+    assert_eq!(
+        KnapsackError::UnknownAlgorithm {
+            name: "Unknown".to_string(),
+            valid_names: vec!["Dynamic".to_string(), "Greedy".to_string()],
+        }
+        .to_string(),
+        "Can't find algorithm name: Unknown (valid names: Dynamic, Greedy)"
+    );
+}
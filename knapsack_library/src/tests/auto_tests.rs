@@ -0,0 +1,134 @@
+use crate::algorithms_impls::auto::AutoKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::auto_decision::AutoChoice;
+use crate::models::instance_features::InstanceFeatures;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+const BUDGET: u64 = 1 << 30;
+
+#[test]
+// We check the features computed for a knapsack.
+fn test_instance_features() {
+    let knapsack = Knapsack::new(10, vec![Item::new(2, 4), Item::new(4, 8), Item::new(11, 1)]);
+    let features = InstanceFeatures::from_knapsack(&knapsack);
+    assert_eq!(features.get_items_len(), 3);
+    assert_eq!(features.get_weight_sum(), 17);
+    assert_eq!(features.get_profit_sum(), 12);
+    assert!(!features.has_copies());
+    assert!(features.get_correlation() < 0.0);
+
+    let knapsack = Knapsack::new(10, vec![Item::new(1, 2), Item::new(2, 4), Item::with_count(3, 6, 2)]);
+    let features = InstanceFeatures::from_knapsack(&knapsack);
+    assert!((features.get_correlation() - 1.0).abs() < 1e-9);
+    assert!(features.has_copies());
+    assert_eq!(features.get_weight_sum(), 9);
+}
+
+#[test]
+// We check every selection rule on features without solving anything.
+fn test_selection_rules() {
+    let select = |features: InstanceFeatures| AutoKnapsackSolver::select(&features, BUDGET).get_choice();
+
+    assert_eq!(select(InstanceFeatures::new(100, 1000, 5000, 5000, 1.0, true, false)), AutoChoice::BoundedDynamic);
+    assert_eq!(select(InstanceFeatures::new(100, 1000, 1000, 5000, 1.0, false, false)), AutoChoice::Greedy);
+    assert_eq!(select(InstanceFeatures::new(100, 1000, 5000, 5000, 1.0, false, true)), AutoChoice::SubsetSum);
+    assert_eq!(select(InstanceFeatures::new(16, 1000, 5000, 5000, 1.0, false, false)), AutoChoice::BitMask);
+    assert_eq!(select(InstanceFeatures::new(100, 1000, 5000, 5000, 0.3, false, false)), AutoChoice::BranchAndBound);
+    assert_eq!(select(InstanceFeatures::new(100, 1000, 5000, 5000, 0.99, false, false)), AutoChoice::Dynamic);
+    assert_eq!(select(InstanceFeatures::new(100, 1 << 40, 1 << 41, 5000, 0.99, false, false)), AutoChoice::ProfitDynamic);
+    assert_eq!(select(InstanceFeatures::new(40, 1 << 40, 1 << 41, 1 << 40, 0.99, false, false)), AutoChoice::MeetInTheMiddle);
+    assert_eq!(select(InstanceFeatures::new(100, 1 << 40, 1 << 41, 1 << 40, 0.99, false, false)), AutoChoice::BranchAndBound);
+}
+
+#[test]
+// We check that the memory budget moves the selection to algorithms with smaller tables.
+fn test_memory_budget() {
+    let features = InstanceFeatures::new(30, 10_000_000, 20_000_000, 10_000_000, 0.99, false, false);
+
+    let decision = AutoKnapsackSolver::select(&features, BUDGET);
+    assert_eq!(decision.get_choice(), AutoChoice::Dynamic);
+    assert!(decision.get_rationale().contains("capacity 10000000"), "{}", decision.get_rationale());
+
+    let decision = AutoKnapsackSolver::select(&features, 1 << 22);
+    assert_eq!(decision.get_choice(), AutoChoice::MeetInTheMiddle);
+
+    let decision = AutoKnapsackSolver::select(&features, 1 << 10);
+    assert_eq!(decision.get_choice(), AutoChoice::BranchAndBound);
+    assert!(decision.get_rationale().contains("no table fits"), "{}", decision.get_rationale());
+
+    // The default budget is computed once and shared by all solvers
+    let budget = AutoKnapsackSolver::new().get_memory_budget();
+    assert!(budget > 0 && budget <= (1 << 33));
+    assert_eq!(AutoKnapsackSolver::default().get_memory_budget(), budget);
+}
+
+#[test]
+// We check that items with copies aren't sent to the bounded dynamic programming if its table doesn't fit the budget.
+fn test_copies_memory_budget() {
+    let knapsack = Knapsack::new(1_000_000_000_000, vec![Item::with_count(3, 5, 2), Item::new(7, 9)]);
+    let solver = AutoKnapsackSolver::with_memory_budget(1 << 20);
+
+    assert_eq!(solver.decide(&knapsack).get_choice(), AutoChoice::Unsupported);
+    assert_eq!(solver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+    assert_eq!(solver.solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+
+    let knapsack = Knapsack::new(1000, vec![Item::with_count(3, 5, 2), Item::new(7, 9)]);
+    assert_eq!(solver.decide(&knapsack).get_choice(), AutoChoice::BoundedDynamic);
+    assert_eq!(solver.solve(&knapsack), Ok(19));
+}
+
+#[test]
+// We check that subset-sum knapsacks beyond the capacity limit of the bitset solver fall through to the other rules.
+fn test_subset_sum_capacity_limit() {
+    let items = vec![Item::new(1 << 29, 1 << 29), Item::new(3 << 28, 3 << 28), Item::new(5, 5)];
+    let knapsack = Knapsack::new(1 << 30, items.clone());
+    let solver = AutoKnapsackSolver::with_memory_budget(1 << 33);
+
+    assert_eq!(solver.decide(&knapsack).get_choice(), AutoChoice::BitMask);
+    assert_eq!(solver.solve(&knapsack), Ok((3 << 28) + 5));
+
+    let knapsack = Knapsack::new((1 << 30) - 1, items);
+    assert_eq!(solver.decide(&knapsack).get_choice(), AutoChoice::SubsetSum);
+}
+
+#[test]
+// We check that the selected algorithms give the optimal value on uncorrelated and correlated knapsacks.
+fn test_same_as_dynamic() {
+    let mut random = Random::new(15);
+
+    for round in 0..20 {
+        let items: Vec<Item> = (0..25)
+            .map(|_| {
+                let weight = random.next(100);
+                let value = if round % 2 == 0 { random.next(100) } else { weight + 10 };
+                Item::new(weight, value)
+            })
+            .collect();
+        let knapsack = Knapsack::new(random.next(1000), items);
+        let expected = DynamicKnapsackSolver.solve(&knapsack);
+
+        for budget in [BUDGET, 1 << 16, 0] {
+            let solver = AutoKnapsackSolver::with_memory_budget(budget);
+            let (solution, decision) = solver.solve_with_decision(&knapsack).unwrap();
+            assert_eq!(Ok(solution.get_total_value()), expected, "{:?}", decision);
+            assert_eq!(solver.solve(&knapsack), expected, "{:?}", decision);
+        }
+    }
+}
+
+#[test]
+// We check that the service returns the decision with the solution.
+fn test_service_solve_auto() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10), Item::new(3, 7), Item::new(3, 5)]);
+
+    let (solution, decision) = AlgorithmsService::solve_auto(&knapsack).unwrap();
+    assert_eq!(solution.get_total_value(), 17);
+    assert_eq!(decision.get_choice(), AutoChoice::BitMask);
+    assert!(!decision.get_rationale().is_empty());
+    assert_eq!(AlgorithmsService::solve("auto".to_string(), &knapsack), Ok(17));
+}
@@ -0,0 +1,68 @@
+use crate::algorithms_impls::bounded_dynamic::BoundedDynamicKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+// We check that the algorithm matches the 0/1 dynamic programming over expanded copies.
+fn test_same_as_expanded_copies() {
+    let mut random = Random::new(11);
+
+    for _ in 0..20 {
+        let items: Vec<Item> = (0..8).map(|_| Item::with_count(random.next(40), random.next(100), random.next(10))).collect();
+        let expanded: Vec<Item> = items
+            .iter()
+            .flat_map(|item| (0..item.get_count()).map(|_| Item::new(item.get_weight(), item.get_value())))
+            .collect();
+        let capacity = random.next(400);
+
+        let bounded = Knapsack::new(capacity, items);
+        let expanded = Knapsack::new(capacity, expanded);
+
+        assert_eq!(BoundedDynamicKnapsackSolver.solve(&bounded), DynamicKnapsackSolver.solve(&expanded));
+    }
+}
+
+#[test]
+// We check that the selected copies don't exceed the counts of the items.
+fn test_selected_copies() {
+    let items = vec![Item::with_count(3, 5, 300), Item::with_count(7, 13, 2), Item::new(1, 1)];
+    let knapsack = Knapsack::new(1000, items);
+
+    let solution = BoundedDynamicKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    let copies = |i: usize| solution.get_items().iter().filter(|&&index| index == i).count();
+
+    assert_eq!(solution.get_total_value(), 300 * 5 + 2 * 13 + 1);
+    assert_eq!(copies(0), 300);
+    assert_eq!(copies(1), 2);
+    assert_eq!(copies(2), 1);
+    assert_eq!(Ok(solution.get_total_value()), BoundedDynamicKnapsackSolver.solve(&knapsack));
+}
+
+#[test]
+// We check that plain items can be taken only once.
+fn test_default_count() {
+    let knapsack = Knapsack::new(10, vec![Item::new(3, 5)]);
+
+    assert_eq!(knapsack.get_item(0).get_count(), 1);
+    assert_eq!(BoundedDynamicKnapsackSolver.solve(&knapsack), Ok(5));
+}
+
+#[test]
+fn test_err_on_large_capacity() {
+    let knapsack = Knapsack::new(u64::MAX, vec![Item::with_count(1, 1, 5)]);
+
+    assert_eq!(BoundedDynamicKnapsackSolver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+
+    // The capacity fits in usize, but the table would exceed the declared limit
+    let knapsack = Knapsack::new(1_000_000_000_000, vec![Item::with_count(3, 5, 2), Item::new(7, 9)]);
+    assert_eq!(BoundedDynamicKnapsackSolver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+    assert_eq!(BoundedDynamicKnapsackSolver.solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+
+    // The capacity is below the limit, but the bitsets of decisions of 100 bundles would exceed it
+    let knapsack = Knapsack::new(1_000_000_000, vec![Item::new(20_000_000, 1); 100]);
+    assert_eq!(BoundedDynamicKnapsackSolver.solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+}
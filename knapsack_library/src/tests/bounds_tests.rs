@@ -0,0 +1,135 @@
+use crate::algorithms_impls::bounds::{
+    dantzig_bound, lagrangian_bound, martello_toth_bound, multiple_surrogate_bound, optimality_gap, surrogate_bound,
+};
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::multi_dimensional_dynamic::MultiDimensionalDynamicKnapsackSolver;
+use crate::algorithms_impls::multiple_branch_and_bound::MultipleBranchAndBoundKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::multi_dimensional_item::MultiDimensionalItem;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solver::MultiDimensionalKnapsackSolver;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solver::MultipleKnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+// We check the bounds on small knapsacks, in the second one the Martello–Toth bound is tighter than the Dantzig one.
+fn test_small_knapsack() {
+    // Sorted by ratio: (5, 30), (5, 10), (6, 12); the critical item is the third one
+    let knapsack = Knapsack::new(11, vec![Item::new(5, 10), Item::new(6, 12), Item::new(5, 30)]);
+
+    assert_eq!(dantzig_bound(&knapsack), 42);
+    assert_eq!(martello_toth_bound(&knapsack), 42);
+    assert_eq!(DynamicKnapsackSolver.solve(&knapsack), Ok(42));
+
+    let knapsack = Knapsack::new(9, vec![Item::new(5, 10), Item::new(6, 9), Item::new(4, 3)]);
+    assert_eq!(dantzig_bound(&knapsack), 16);
+    assert_eq!(martello_toth_bound(&knapsack), 15);
+    assert_eq!(DynamicKnapsackSolver.solve(&knapsack), Ok(13));
+}
+
+#[test]
+// We check that the bounds are never below the optimum and the Martello–Toth bound is never above the Dantzig one.
+fn test_bounds_above_optimum() {
+    let mut random = Random::new(19);
+    for _ in 0..200 {
+        let n = random.next(12) as usize;
+        let items = (0..n).map(|_| Item::new(random.next(30) - 1, random.next(50) - 1)).collect();
+        let knapsack = Knapsack::new(random.next(80), items);
+        let optimum = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        let dantzig = dantzig_bound(&knapsack);
+        let martello_toth = martello_toth_bound(&knapsack);
+        assert!(optimum <= martello_toth && martello_toth <= dantzig);
+        for multiplier in [(0, 1), (1, 1), (3, 2), (7, 1)] {
+            assert!(optimum <= lagrangian_bound(&knapsack, multiplier).unwrap());
+        }
+    }
+}
+
+#[test]
+// We check that the bounds take the copies of bounded items into account.
+fn test_bounded_items() {
+    let knapsack = Knapsack::new(10, vec![Item::with_count(3, 5, 4), Item::with_count(4, 4, 2)]);
+
+    // Three copies of the first item fit, and a quarter of the second one
+    assert_eq!(dantzig_bound(&knapsack), 16);
+    assert_eq!(martello_toth_bound(&knapsack), 16);
+}
+
+#[test]
+// We check that the Lagrangian bound at the ratio of the critical item equals the Dantzig bound.
+fn test_lagrangian_bound() {
+    let knapsack = Knapsack::new(10, vec![Item::new(6, 12), Item::new(6, 11), Item::new(4, 4)]);
+
+    assert_eq!(lagrangian_bound(&knapsack, (11, 6)), Ok(dantzig_bound(&knapsack)));
+    assert_eq!(lagrangian_bound(&knapsack, (0, 1)), Ok(27));
+    assert!(matches!(lagrangian_bound(&knapsack, (1, 0)), Err(KnapsackError::InvalidParameter(_))));
+}
+
+#[test]
+// We check that the surrogate bound is never below the optimum of a multi-dimensional knapsack.
+fn test_surrogate_bound() {
+    let mut random = Random::new(7);
+    for _ in 0..50 {
+        let n = random.next(8) as usize;
+        let items = (0..n)
+            .map(|_| MultiDimensionalItem::new(vec![random.next(10), random.next(10)], random.next(20)))
+            .collect();
+        let knapsack = MultiDimensionalKnapsack::new(vec![random.next(20), random.next(20)], items).unwrap();
+        let optimum = MultiDimensionalDynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        for multipliers in [[1, 0], [0, 1], [1, 1], [2, 3]] {
+            assert!(optimum <= surrogate_bound(&knapsack, &multipliers).unwrap());
+        }
+    }
+
+    let knapsack = MultiDimensionalKnapsack::new(vec![5, 5], vec![MultiDimensionalItem::new(vec![1, 2], 3)]).unwrap();
+    assert!(matches!(surrogate_bound(&knapsack, &[1]), Err(KnapsackError::InvalidParameter(_))));
+    assert_eq!(surrogate_bound(&knapsack, &[u64::MAX, 1]), Err(KnapsackError::ArithmeticOverflow));
+}
+
+#[test]
+// We check that the surrogate bound is never below the optimum of a multiple knapsack problem.
+fn test_multiple_surrogate_bound() {
+    let mut random = Random::new(3);
+    for _ in 0..50 {
+        let n = random.next(8) as usize;
+        let items = (0..n).map(|_| Item::new(random.next(15), random.next(20))).collect();
+        let knapsack = MultipleKnapsack::new(vec![random.next(15), random.next(15)], items);
+        let optimum = MultipleBranchAndBoundKnapsackSolver.solve(&knapsack).unwrap();
+
+        assert!(optimum <= multiple_surrogate_bound(&knapsack));
+    }
+}
+
+#[test]
+// We check the optimality gap of the optimum and of a poor solution.
+fn test_optimality_gap() {
+    let knapsack = Knapsack::new(11, vec![Item::new(5, 10), Item::new(6, 12), Item::new(5, 30)]);
+
+    assert_eq!(optimality_gap(&knapsack, 42), 0.0);
+    assert!((optimality_gap(&knapsack, 21) - 0.5).abs() < 1e-9);
+    assert_eq!(optimality_gap(&Knapsack::new(10, vec![]), 0), 0.0);
+}
+
+#[test]
+// We check that the bounds saturate at u64::MAX instead of overflowing when the values are near it.
+fn test_values_near_max() {
+    let large = u64::MAX / 2 + 10;
+    let items = vec![Item::new(1, large), Item::new(1, large), Item::new(2, 5)];
+    let knapsack = Knapsack::new(3, items.clone());
+
+    assert_eq!(dantzig_bound(&knapsack), u64::MAX);
+    assert_eq!(martello_toth_bound(&knapsack), u64::MAX);
+    assert_eq!(optimality_gap(&knapsack, u64::MAX), 0.0);
+    assert_eq!(multiple_surrogate_bound(&MultipleKnapsack::new(vec![2, 1], items)), u64::MAX);
+
+    // The bounds are still valid when the sums fit
+    let knapsack = Knapsack::new(2, vec![Item::new(1, u64::MAX - 10), Item::new(2, 5), Item::new(3, 4)]);
+    let bound = martello_toth_bound(&knapsack);
+    assert!(bound >= u64::MAX - 10 && bound <= dantzig_bound(&knapsack));
+}
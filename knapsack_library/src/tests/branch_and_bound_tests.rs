@@ -0,0 +1,48 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_recursion::RecursiveKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+fn random_knapsack(seed: u64, items_count: usize, capacity: u64) -> Knapsack {
+    let mut random = Random::new(seed);
+    let items: Vec<Item> = (0..items_count).map(|_| Item::new(random.next(1000), random.next(1000))).collect();
+    Knapsack::new(capacity, items)
+}
+
+#[test]
+// We check that the algorithm matches the dynamic programming on larger instances.
+fn test_same_as_dynamic() {
+    for seed in 0..10 {
+        let knapsack = random_knapsack(seed, 200, 20_000);
+        assert_eq!(BranchAndBoundKnapsackSolver.solve(&knapsack), DynamicKnapsackSolver.solve(&knapsack));
+    }
+}
+
+#[test]
+// We check that the pruning visits fewer nodes than the full recursion tree.
+fn test_explores_fewer_nodes_than_full_iteration() {
+    let knapsack = random_knapsack(1, 20, 5000);
+    let stats = BranchAndBoundKnapsackSolver.solve_with_stats(&knapsack).unwrap();
+
+    assert_eq!(Ok(stats.get_solution().get_total_value()), RecursiveKnapsackSolver.solve(&knapsack));
+    assert!(stats.get_nodes_explored() > 0);
+    assert!(stats.get_nodes_explored() < 1 << 20);
+}
+
+#[test]
+// We check that items without weight or value don't break the ratio ordering.
+fn test_zero_weights_and_values() {
+    let items = vec![
+        Item::new(0, 0),
+        Item::new(0, 5),
+        Item::new(4, 0),
+        Item::new(3, 4),
+        Item::new(2, 3),
+    ];
+    let knapsack = Knapsack::new(5, items);
+
+    assert_eq!(BranchAndBoundKnapsackSolver.solve(&knapsack), Ok(12));
+}
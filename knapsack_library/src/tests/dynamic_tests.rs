@@ -3,6 +3,7 @@ mod tests {
     use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
     use crate::models::item::Item;
     use crate::models::knapsack::Knapsack;
+    use crate::models::knapsack_error::KnapsackError;
     use crate::models::knapsack_solver::KnapsackSolver;
 
     #[test]
@@ -11,8 +12,26 @@ mod tests {
         let items = vec![Item::new(1, 1)];
         let knapsack = Knapsack::new(u64::MAX, items);
 
-        assert_eq!(solver.solve(&knapsack), Err("Capacity too large to process".to_string()));
+        assert_eq!(solver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+        assert_eq!(solver.solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+
+        // The capacity is below the limit, but the bitsets of decisions of 100 items would exceed it
+        let items = vec![Item::new(20_000_000, 1); 100];
+        let knapsack = Knapsack::new(1_000_000_000, items);
+        assert_eq!(solver.solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
     }
 
+    #[test]
+    // We check that the backtracking restores the items across bitset word boundaries.
+    fn test_selected_items_with_large_capacity() {
+        let solver = DynamicKnapsackSolver;
+        let items = vec![Item::new(70, 10), Item::new(64, 9), Item::new(63, 9), Item::new(1, 1)];
+        let knapsack = Knapsack::new(128, items);
+
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        assert_eq!(solution.get_items(), &[1, 2, 3]);
+        assert_eq!(solution.get_total_weight(), 128);
+        assert_eq!(solution.get_total_value(), 19);
+    }
 
 }
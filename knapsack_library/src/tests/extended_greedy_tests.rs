@@ -0,0 +1,41 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::extended_greedy::ExtendedGreedyKnapsackSolver;
+use crate::algorithms_impls::greedy::GreedyKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+// We check that the best single item is taken when the greedy solution is worse.
+fn test_single_item() {
+    // The greedy takes the small item with the best ratio, which leaves no room for the large one
+    let knapsack = Knapsack::new(10, vec![Item::new(1, 2), Item::new(10, 10)]);
+
+    assert_eq!(GreedyKnapsackSolver.solve(&knapsack), Ok(2));
+    let solution = ExtendedGreedyKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[1]);
+    assert_eq!(solution.get_total_value(), 10);
+
+    // Items which don't fit aren't considered
+    let knapsack = Knapsack::new(10, vec![Item::new(1, 2), Item::new(11, 100)]);
+    assert_eq!(ExtendedGreedyKnapsackSolver.solve(&knapsack), Ok(2));
+    assert_eq!(ExtendedGreedyKnapsackSolver.solve(&Knapsack::new(10, vec![])), Ok(0));
+}
+
+#[test]
+// We check that the solution is never worse than the greedy one and at least half of the optimum.
+fn test_half_of_optimum() {
+    let mut random = Random::new(11);
+
+    for _ in 0..100 {
+        let items: Vec<Item> = (0..12).map(|_| Item::new(random.next(40), random.next(40))).collect();
+        let knapsack = Knapsack::new(random.next(100), items);
+        let optimum = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        let solution = ExtendedGreedyKnapsackSolver.solve_with_items(&knapsack).unwrap();
+        assert!(solution.get_total_weight() <= knapsack.get_capacity());
+        assert!(solution.get_total_value() >= GreedyKnapsackSolver.solve(&knapsack).unwrap());
+        assert!(2 * solution.get_total_value() >= optimum);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::fptas::FptasKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+fn test_err_on_invalid_epsilon() {
+    let knapsack = Knapsack::new(10, vec![Item::new(1, 1)]);
+
+    for epsilon in [0.0, 1.0, -0.5, f64::NAN] {
+        let solver = FptasKnapsackSolver::new(epsilon);
+        assert_eq!(solver.solve(&knapsack), Err(KnapsackError::InvalidParameter("epsilon must be in the range (0, 1)".to_string())));
+    }
+}
+
+#[test]
+// We check that the bitsets of decisions are bounded: 1000 items with ε = 0.01 give 10^8 scaled values.
+fn test_err_on_large_decisions() {
+    let knapsack = Knapsack::new(500, vec![Item::new(1, 1_000_000_000); 1000]);
+
+    assert_eq!(FptasKnapsackSolver::new(0.01).solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+}
+
+#[test]
+fn test_name_contains_epsilon() {
+    assert_eq!(FptasKnapsackSolver::new(0.1).get_name(), "FPTAS 0.1");
+}
+
+#[test]
+// We check that the result is at least (1 - ε) of the optimal value.
+fn test_approximation_guarantee() {
+    let mut random = Random::new(7);
+
+    for _ in 0..20 {
+        let items: Vec<Item> = (0..40).map(|_| Item::new(random.next(100), random.next(100_000))).collect();
+        let knapsack = Knapsack::new(1000, items);
+        let optimal = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        for epsilon in [0.5, 0.1, 0.01] {
+            let result = FptasKnapsackSolver::new(epsilon).solve(&knapsack).unwrap();
+            assert!(result <= optimal);
+            assert!(result as f64 >= (1.0 - epsilon) * optimal as f64);
+        }
+    }
+}
+
+#[test]
+// We check that the algorithm doesn't depend on the capacity.
+fn test_huge_capacity() {
+    let solver = FptasKnapsackSolver::new(0.1);
+    let items = vec![
+        Item::new(u64::MAX / 2, 10),
+        Item::new(u64::MAX / 2, 7),
+        Item::new(3, 5),
+    ];
+    let knapsack = Knapsack::new(u64::MAX - 1, items);
+
+    assert_eq!(solver.solve(&knapsack), Ok(17));
+}
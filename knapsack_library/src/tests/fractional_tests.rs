@@ -0,0 +1,81 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+/// The fractional knapsack value computed by sorting the items, as a `(numerator, denominator)` pair.
+fn sorted_fractional_value(knapsack: &Knapsack) -> (u128, u128) {
+    let mut items: Vec<&Item> = (0..knapsack.get_items_len()).map(|i| knapsack.get_item(i)).collect();
+    items.sort_by(|a, b| (b.get_value() as u128 * a.get_weight() as u128).cmp(&(a.get_value() as u128 * b.get_weight() as u128)));
+
+    let mut remaining = knapsack.get_capacity();
+    let mut value = 0u128;
+    for item in items {
+        if item.get_weight() <= remaining {
+            remaining -= item.get_weight();
+            value += item.get_value() as u128;
+        } else {
+            let weight = item.get_weight() as u128;
+            return (value * weight + item.get_value() as u128 * remaining as u128, weight);
+        }
+    }
+    (value, 1)
+}
+
+#[test]
+// We check the critical item and its fraction on a simple knapsack.
+fn test_critical_item() {
+    let items = vec![Item::new(10, 60), Item::new(20, 100), Item::new(30, 120)];
+    let knapsack = Knapsack::new(50, items);
+
+    let solution = AlgorithmsService::solve_fractional(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1]);
+    assert_eq!(solution.get_critical_item(), Some(2));
+    assert_eq!(solution.get_fraction_ratio(), (20, 30));
+    assert_eq!(solution.get_total_weight(), 50);
+    assert_eq!(solution.get_integral_value(), 160);
+    assert!((solution.get_total_value() - 240.0).abs() < 1e-9);
+    assert_eq!(solution.get_upper_bound(), 240);
+}
+
+#[test]
+// We check the case where every item fits, as well as items without weight or value.
+fn test_no_critical_item() {
+    let items = vec![Item::new(1, 2), Item::new(0, 5), Item::new(3, 0), Item::new(2, 2)];
+    let knapsack = Knapsack::new(10, items);
+
+    let solution = AlgorithmsService::solve_fractional(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1, 3]);
+    assert_eq!(solution.get_critical_item(), None);
+    assert_eq!(solution.get_fraction(), 0.0);
+    assert_eq!(solution.get_upper_bound(), 9);
+}
+
+#[test]
+// We check that the selection finds the same value as sorting, and that it bounds the 0/1 optimum.
+fn test_same_as_sorting() {
+    let mut random = Random::new(9);
+
+    for _ in 0..50 {
+        // Few distinct ratios, so that equal ratios are common
+        let items: Vec<Item> = (0..30).map(|_| {
+            let weight = random.next(10);
+            Item::new(weight, weight * random.next(4))
+        }).collect();
+        let knapsack = Knapsack::new(random.next(150), items);
+
+        let solution = AlgorithmsService::solve_fractional(&knapsack).unwrap();
+        let (numerator, denominator) = sorted_fractional_value(&knapsack);
+        let (fraction_numerator, fraction_denominator) = solution.get_fraction_ratio();
+        let critical_value = solution.get_critical_item().map_or(0, |i| knapsack.get_item(i).get_value());
+        // Compare integral + critical_value * fn / fd with numerator / denominator exactly
+        let value = solution.get_integral_value() as u128 * fraction_denominator as u128
+            + critical_value as u128 * fraction_numerator as u128;
+        assert_eq!(value * denominator, numerator * fraction_denominator as u128);
+
+        assert!(solution.get_total_weight() <= knapsack.get_capacity());
+        assert!(solution.get_upper_bound() >= DynamicKnapsackSolver.solve(&knapsack).unwrap());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
 use crate::models::item::Item;
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
 use crate::models::knapsack_solver::KnapsackSolver;
 
 #[test]
@@ -12,5 +13,15 @@ fn test_err_on_more_than_64_items() {
     }
     let knapsack = Knapsack::new(32, items);
 
-    assert_eq!(solver.solve(&knapsack), Err("The number of items exceeds the maximum allowed (64).".to_string()));
-}
\ No newline at end of file
+    assert_eq!(solver.solve(&knapsack), Err(KnapsackError::TooManyItems { items: 66, max: 63 }));
+}
+
+#[test]
+// We check that 64 items are rejected, since the masks of all their subsets don't fit in a u64.
+fn test_err_on_64_items() {
+    let items: Vec<Item> = (0..64).map(|i| Item::new(i, i)).collect();
+    let knapsack = Knapsack::new(32, items);
+
+    assert_eq!(BitMaskKnapsackSolver.solve(&knapsack), Err(KnapsackError::TooManyItems { items: 64, max: 63 }));
+    assert!(BitMaskKnapsackSolver.get_capabilities().check(&knapsack).is_err());
+}
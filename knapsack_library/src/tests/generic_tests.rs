@@ -0,0 +1,112 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::generic_branch_and_bound::GenericBranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::generic_dynamic::GenericDynamicKnapsackSolver;
+use crate::algorithms_impls::generic_greedy::GenericGreedyKnapsackSolver;
+use crate::models::generic_item::GenericItem;
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::generic_knapsack_solver::GenericKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::numeric::Numeric;
+use crate::tests::Random;
+use std::cmp::Ordering;
+
+/// Generates random `(weight, value)` pairs.
+fn random_pairs(random: &mut Random, n: usize, max: u64) -> Vec<(u64, u64)> {
+    (0..n).map(|_| (random.next(max), random.next(max))).collect()
+}
+
+#[test]
+// We check that the generic solvers agree with the dynamic programming for every integer type.
+fn test_integer_types() {
+    let mut random = Random::new(7);
+    for _ in 0..20 {
+        let pairs = random_pairs(&mut random, 15, 50);
+        let capacity = pairs.iter().map(|&(w, _)| w).sum::<u64>() / 2;
+        let knapsack = Knapsack::new(capacity, pairs.iter().map(|&(w, v)| Item::new(w, v)).collect());
+        let optimum = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        let small = GenericKnapsack::new(capacity as u32, pairs.iter().map(|&(w, v)| GenericItem::new(w as u32, v as u32)).collect());
+        assert_eq!(GenericDynamicKnapsackSolver.solve(&small), Ok(optimum as u32));
+        assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&small), Ok(optimum as u32));
+        assert!(GenericGreedyKnapsackSolver.solve(&small).unwrap() <= optimum as u32);
+
+        let large = GenericKnapsack::new(capacity as u128, pairs.iter().map(|&(w, v)| GenericItem::new(w as u128, v as u128)).collect());
+        let solution = GenericDynamicKnapsackSolver.solve_with_items(&large).unwrap();
+        assert_eq!(solution.get_total_value(), optimum as u128);
+        assert!(solution.get_total_weight() <= capacity as u128);
+        assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&large), Ok(optimum as u128));
+
+        let knapsack = GenericKnapsack::new(capacity, pairs.iter().map(|&(w, v)| GenericItem::new(w, v)).collect());
+        assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Ok(optimum));
+    }
+}
+
+#[test]
+// We check that the ratio-based solvers accept fractional prices.
+fn test_floating_point() {
+    let items = vec![
+        GenericItem::new(1.5, 10.25),
+        GenericItem::new(2.0, 12.5),
+        GenericItem::new(2.5, 14.75),
+        GenericItem::new(0.0, 0.5),
+        GenericItem::new(7.0, 100.0),
+    ];
+    let knapsack = GenericKnapsack::new(4.0, items);
+
+    // The first, third and zero-weight items
+    let solution = GenericBranchAndBoundKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 2, 3]);
+    assert_eq!(solution.get_total_weight(), 4.0);
+    assert_eq!(solution.get_total_value(), 25.5);
+
+    let greedy = GenericGreedyKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    assert!(greedy.get_total_weight() <= 4.0);
+    assert!(greedy.get_total_value() <= 25.5);
+
+    let knapsack = GenericKnapsack::new(4.0f32, vec![GenericItem::new(2.5, 3.0), GenericItem::new(2.0, 2.0), GenericItem::new(2.0, 2.0)]);
+    assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Ok(4.0));
+}
+
+#[test]
+// We check that the ratios of large integers are compared exactly.
+fn test_compare_ratios() {
+    let max = u128::MAX;
+    assert_eq!(u128::compare_ratios((max, max - 1), (max - 1, max - 2)), Ordering::Greater);
+    assert_eq!(u128::compare_ratios((max - 1, max - 2), (max, max - 1)), Ordering::Less);
+    assert_eq!(u128::compare_ratios((max - 1, max - 1), (3, 3)), Ordering::Equal);
+    assert_eq!(u64::compare_ratios((3, 10), (7, 23)), Ordering::Greater);
+    assert_eq!(u32::compare_ratios((6, 20), (3, 10)), Ordering::Equal);
+
+    // The ratios differ by less than the precision of f64, and the greedy takes the second item only in the exact order
+    let items = vec![GenericItem::new(max - 1, max / 2 - 1), GenericItem::new(max, max / 2), GenericItem::new(1, 0)];
+    let knapsack = GenericKnapsack::new(max, items);
+    assert_eq!(GenericGreedyKnapsackSolver.solve(&knapsack), Ok(max / 2));
+    assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Ok(max / 2));
+}
+
+#[test]
+// We check that invalid numbers and overflowing values are reported.
+fn test_validation() {
+    let knapsack = GenericKnapsack::new(5.0, vec![GenericItem::new(1.0, f64::NAN)]);
+    assert!(matches!(GenericGreedyKnapsackSolver.solve(&knapsack), Err(KnapsackError::InvalidInstance(_))));
+    let knapsack = GenericKnapsack::new(5.0, vec![GenericItem::new(-1.0, 1.0)]);
+    assert!(matches!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Err(KnapsackError::InvalidInstance(_))));
+    let knapsack = GenericKnapsack::new(f64::INFINITY, vec![GenericItem::new(1.0, 1.0)]);
+    assert!(matches!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Err(KnapsackError::InvalidInstance(_))));
+    let knapsack = GenericKnapsack::new(2.0, vec![GenericItem::new(1.0, f64::MAX), GenericItem::new(1.0, f64::MAX)]);
+    assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow));
+
+    let knapsack = GenericKnapsack::new(2u32, vec![GenericItem::new(1, u32::MAX), GenericItem::new(1, 1)]);
+    assert_eq!(GenericDynamicKnapsackSolver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow));
+    let knapsack = GenericKnapsack::new(u128::MAX, vec![GenericItem::new(1, 1)]);
+    assert_eq!(GenericDynamicKnapsackSolver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+    assert_eq!(GenericBranchAndBoundKnapsackSolver.solve(&knapsack), Ok(1));
+    let knapsack = GenericKnapsack::new(1u64 << 30, vec![GenericItem::new(1, 1)]);
+    assert_eq!(GenericDynamicKnapsackSolver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+    // The capacity is below the limit, but the bitsets of decisions of 100 items would exceed it
+    let knapsack = GenericKnapsack::new(1_000_000_000u64, vec![GenericItem::new(20_000_000, 1); 100]);
+    assert_eq!(GenericDynamicKnapsackSolver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
+}
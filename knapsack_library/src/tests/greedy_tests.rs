@@ -16,3 +16,27 @@ fn test_err_on_large_capacity() {
 
     assert_eq!(solver.solve(&knapsack), Ok(7));
 }
+
+#[test]
+// We check that items without weight are taken and items without weight and value don't break the sorting.
+fn test_zero_weights() {
+    let solver = GreedyKnapsackSolver;
+    let items = vec![Item::new(0, 0), Item::new(3, 4), Item::new(0, 5), Item::new(0, 0), Item::new(4, 0), Item::new(2, 1)];
+    let knapsack = Knapsack::new(6, items);
+
+    let solution = solver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[1, 2, 5]);
+    assert_eq!(solution.get_total_value(), 10);
+}
+
+#[test]
+// We check that ratios closer than the precision of f64 are still ordered correctly.
+fn test_exact_ratios() {
+    let solver = GreedyKnapsackSolver;
+    let max = u64::MAX;
+    // The ratios are 1/2 - 1/(max - 1) and 1/2 - 1/(2 * max), which are equal as f64
+    let items = vec![Item::new(max - 1, max / 2 - 1), Item::new(max, max / 2)];
+    let knapsack = Knapsack::new(max, items);
+
+    assert_eq!(solver.solve(&knapsack), Ok(max / 2));
+}
@@ -0,0 +1,122 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::generic_dynamic::GenericDynamicKnapsackSolver;
+use crate::algorithms_impls::multi_dimensional_dynamic::MultiDimensionalDynamicKnapsackSolver;
+use crate::algorithms_impls::multiple_branch_and_bound::MultipleBranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::preprocessed::PreprocessedKnapsackSolver;
+use crate::algorithms_impls::preprocessing::Preprocessor;
+use crate::algorithms_impls::unbounded_dynamic::UnboundedDynamicKnapsackSolver;
+use crate::models::generic_item::GenericItem;
+use crate::models::generic_knapsack::GenericKnapsack;
+use crate::models::generic_knapsack_solver::GenericKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solution::KnapsackSolution;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::multi_dimensional_item::MultiDimensionalItem;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multi_dimensional_knapsack_solver::MultiDimensionalKnapsackSolver;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solver::MultipleKnapsackSolver;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::models::unbounded_knapsack_solver::UnboundedKnapsackSolver;
+
+/// A knapsack whose items have identifiers and labels in a different order than their indices.
+fn knapsack() -> Knapsack {
+    Knapsack::new(
+        20,
+        vec![
+            Item::new(6, 5).with_id(104).with_label("lamp"),
+            Item::new(9, 12).with_id(101).with_label("tent"),
+            Item::new(12, 8).with_id(103),
+            Item::new(3, 4).with_id(102).with_label("stove"),
+        ],
+    )
+}
+
+#[test]
+// We check that the items without identifiers aren't given their indices, which could collide with explicit identifiers.
+fn test_default_ids() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10), Item::new(4, 8).with_id(0), Item::new(3, 1)]);
+    assert_eq!(knapsack.get_item(0).get_id(), None);
+    assert_eq!(knapsack.get_item(0).get_label(), None);
+    assert_eq!(knapsack.get_item_id(0), None);
+
+    let solution = DynamicKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1]);
+    assert_eq!(solution.get_item_ids(), &[None, Some(0)]);
+}
+
+#[test]
+// We check that the solutions report the identifiers of the selected items, also after preprocessing.
+fn test_solution_ids() {
+    let knapsack = knapsack();
+    let solution = BranchAndBoundKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1, 3]);
+    assert_eq!(solution.get_item_ids(), &[Some(104), Some(101), Some(102)]);
+
+    let reduced = Preprocessor::new().reduce(&knapsack);
+    for (i, &original) in reduced.get_original_indices().iter().enumerate() {
+        let item = reduced.get_knapsack().get_item(i);
+        assert_eq!(item.get_id(), knapsack.get_item(original).get_id());
+        assert_eq!(item.get_label(), knapsack.get_item(original).get_label());
+    }
+
+    let solver = PreprocessedKnapsackSolver::new(Box::new(DynamicKnapsackSolver));
+    assert_eq!(solver.solve_with_items(&knapsack), Ok(solution));
+}
+
+#[test]
+// We check that the solutions of the other kinds of knapsacks report the identifiers too.
+fn test_other_solution_ids() {
+    let items = vec![Item::new(4, 5).with_id(7), Item::new(3, 3), Item::new(5, 6).with_id(9)];
+
+    let unbounded = UnboundedKnapsack::new(8, items.clone());
+    let solution = UnboundedDynamicKnapsackSolver.solve_with_items(&unbounded).unwrap();
+    assert_eq!(solution.get_counts(), &[2, 0, 0]);
+    assert_eq!(solution.get_item_ids(), &[Some(7), None, Some(9)]);
+
+    let multiple = MultipleKnapsack::new(vec![4, 5], items);
+    let solution = MultipleBranchAndBoundKnapsackSolver.solve_with_items(&multiple).unwrap();
+    assert_eq!(solution.get_assignments(), &[Some(0), None, Some(1)]);
+    assert_eq!(solution.get_item_ids(), &[Some(7), None, Some(9)]);
+
+    let multi_dimensional = MultiDimensionalKnapsack::new(
+        vec![5, 5],
+        vec![
+            MultiDimensionalItem::new(vec![3, 2], 4).with_id(11),
+            MultiDimensionalItem::new(vec![2, 3], 4),
+            MultiDimensionalItem::new(vec![4, 4], 5).with_id(13),
+        ],
+    )
+    .unwrap();
+    let solution = MultiDimensionalDynamicKnapsackSolver.solve_with_items(&multi_dimensional).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1]);
+    assert_eq!(solution.get_item_ids(), &[Some(11), None]);
+
+    let generic = GenericKnapsack::new(7u32, vec![GenericItem::new(3, 4).with_id(21), GenericItem::new(4, 5), GenericItem::new(5, 6).with_id(23)]);
+    let solution = GenericDynamicKnapsackSolver.solve_with_items(&generic).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1]);
+    assert_eq!(solution.get_item_ids(), &[Some(21), None]);
+}
+
+#[test]
+// We check that the identifiers and labels are serialized, and that the old format is still read.
+fn test_serde() {
+    let knapsack = knapsack();
+    let json = serde_json::to_string(&knapsack).unwrap();
+    assert_eq!(serde_json::from_str::<Knapsack>(&json).unwrap(), knapsack);
+
+    let solution = DynamicKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    let json = serde_json::to_string(&solution).unwrap();
+    assert_eq!(serde_json::from_str::<KnapsackSolution>(&json).unwrap(), solution);
+
+    // Unset fields are omitted
+    let json = serde_json::to_string(&Item::new(3, 4)).unwrap();
+    assert_eq!(json, r#"{"weight":3,"value":4,"count":1}"#);
+
+    let old = r#"{"capacity":10,"items":[{"weight":5,"value":10},{"weight":4,"value":8}]}"#;
+    let knapsack: Knapsack = serde_json::from_str(old).unwrap();
+    assert_eq!(knapsack, Knapsack::new(10, vec![Item::new(5, 10), Item::new(4, 8)]));
+    assert_eq!(knapsack.get_item_id(1), None);
+}
@@ -1,6 +1,7 @@
 use crate::algorithms_impls::lazy_dynamic::LazyDynamicKnapsackSolver;
 use crate::models::item::Item;
 use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
 use crate::models::knapsack_solver::KnapsackSolver;
 
 #[test]
@@ -9,5 +10,5 @@ fn test_err_on_large_capacity() {
     let items = vec![Item::new(1, 1)];
     let knapsack = Knapsack::new(u64::MAX, items);
 
-    assert_eq!(solver.solve(&knapsack), Err("Capacity too large to process".to_string()));
+    assert_eq!(solver.solve(&knapsack), Err(KnapsackError::CapacityTooLarge));
 }
@@ -0,0 +1,44 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::meet_in_the_middle::MeetInTheMiddleKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+fn test_err_on_more_than_48_items() {
+    let solver = MeetInTheMiddleKnapsackSolver;
+    let mut items: Vec<Item> = vec!();
+    for i in 0..49 {
+        items.push(Item::new(i, i));
+    }
+    let knapsack = Knapsack::new(32, items);
+
+    assert_eq!(solver.solve(&knapsack), Err(KnapsackError::TooManyItems { items: 49, max: 48 }));
+    assert_eq!(solver.get_capabilities().check(&knapsack), Err(KnapsackError::TooManyItems { items: 49, max: 48 }));
+}
+
+#[test]
+// We check that the algorithm works with a capacity the dynamic programming can't allocate.
+fn test_huge_capacity() {
+    let solver = MeetInTheMiddleKnapsackSolver;
+    let items = vec![
+        Item::new(u64::MAX / 2, 10),
+        Item::new(u64::MAX / 2, 7),
+        Item::new(3, 5),
+    ];
+    let knapsack = Knapsack::new(u64::MAX - 1, items);
+
+    assert_eq!(solver.solve(&knapsack), Ok(17));
+}
+
+#[test]
+// We check that the algorithm matches the dynamic programming on a pseudo-random instance.
+fn test_same_as_dynamic() {
+    let mut random = Random::new(42);
+    let items: Vec<Item> = (0..30).map(|_| Item::new(random.next(100), random.next(100))).collect();
+    let knapsack = Knapsack::new(500, items);
+
+    assert_eq!(MeetInTheMiddleKnapsackSolver.solve(&knapsack), DynamicKnapsackSolver.solve(&knapsack));
+}
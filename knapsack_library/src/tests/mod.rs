@@ -3,4 +3,44 @@ pub mod dynamic_tests;
 pub mod full_iteration_with_recursion_tests;
 pub mod full_iteration_with_bit_mask_tests;
 pub mod lazy_dynamic_tests;
-pub mod greedy_tests;
\ No newline at end of file
+pub mod greedy_tests;
+pub mod meet_in_the_middle_tests;
+pub mod fptas_tests;
+pub mod branch_and_bound_tests;
+pub mod unbounded_tests;
+pub mod bounded_dynamic_tests;
+pub mod multi_dimensional_tests;
+pub mod multiple_tests;
+pub mod fractional_tests;
+pub mod profit_dynamic_tests;
+pub mod algorithms_registry_tests;
+pub mod solver_capabilities_tests;
+pub mod auto_tests;
+pub mod parallel_dynamic_tests;
+pub mod subset_sum_tests;
+pub mod preprocessing_tests;
+pub mod bounds_tests;
+pub mod solve_context_tests;
+pub mod solve_observer_tests;
+pub mod overflow_tests;
+pub mod generic_tests;
+pub mod item_identifiers_tests;
+pub mod extended_greedy_tests;
+
+/// A deterministic pseudo-random number generator, so that the tests use the same instances on every run.
+pub(crate) struct Random {
+    seed: u64,
+}
+
+impl Random {
+    /// Creates a generator with the given seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Generates a pseudo-random number from 1 to `max` and advances the seed.
+    pub(crate) fn next(&mut self, max: u64) -> u64 {
+        self.seed = self.seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.seed >> 33) % max + 1
+    }
+}
@@ -0,0 +1,98 @@
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_item::MultiDimensionalItem;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::multi_dimensional_algorithms_service::MultiDimensionalAlgorithmsService;
+use crate::tests::Random;
+
+/// Full iteration over all subsets of items.
+fn brute_force(knapsack: &MultiDimensionalKnapsack) -> u64 {
+    let mut best = 0;
+    for mask in 0..1u64 << knapsack.get_items_len() {
+        let mut weights = vec![0; knapsack.get_dimensions()];
+        let mut value = 0;
+        for i in (0..knapsack.get_items_len()).filter(|&i| mask & (1 << i) != 0) {
+            weights.iter_mut().zip(knapsack.get_item(i).get_weights()).for_each(|(t, w)| *t += w);
+            value += knapsack.get_item(i).get_value();
+        }
+        if weights.iter().zip(knapsack.get_capacities()).all(|(w, c)| w <= c) {
+            best = best.max(value);
+        }
+    }
+    best
+}
+
+#[test]
+// We check that the vector lengths are validated.
+fn test_validation() {
+    let items = vec![MultiDimensionalItem::new(vec![1, 2], 3), MultiDimensionalItem::new(vec![1], 3)];
+
+    assert_eq!(
+        MultiDimensionalKnapsack::new(vec![10, 10], items),
+        Err(KnapsackError::InvalidInstance("item 1 has 1 weights, but the knapsack has 2 capacities".to_string()))
+    );
+    assert_eq!(
+        MultiDimensionalKnapsack::new(vec![], vec![]),
+        Err(KnapsackError::InvalidInstance("the knapsack must have at least one capacity".to_string()))
+    );
+}
+
+#[test]
+// We check that an item has to fit in every dimension, while the greedy prefers the balanced item.
+fn test_all_constraints_respected() {
+    let items = vec![
+        MultiDimensionalItem::new(vec![5, 1], 10),
+        MultiDimensionalItem::new(vec![1, 5], 10),
+        MultiDimensionalItem::new(vec![4, 4], 15),
+    ];
+    let knapsack = MultiDimensionalKnapsack::new(vec![6, 6], items).unwrap();
+
+    for solver in MultiDimensionalAlgorithmsService::get_all_algorithms() {
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        let expected = if solver.get_name() == "Multi-dimensional greedy" { 15 } else { 20 };
+        assert!(solution.get_total_weights().iter().all(|&w| w <= 6));
+        assert_eq!(solution.get_total_value(), expected, "{}", solver.get_name());
+    }
+}
+
+#[test]
+// We check that the exact algorithms match the full iteration and the greedy one is feasible.
+fn test_same_as_brute_force() {
+    let mut random = Random::new(5);
+
+    for _ in 0..20 {
+        let items: Vec<MultiDimensionalItem> = (0..10)
+            .map(|_| MultiDimensionalItem::new(vec![random.next(20), random.next(20), random.next(20)], random.next(100)))
+            .collect();
+        let knapsack = MultiDimensionalKnapsack::new(vec![random.next(60), random.next(60), random.next(60)], items).unwrap();
+        let expected = brute_force(&knapsack);
+
+        for solver in MultiDimensionalAlgorithmsService::get_all_algorithms() {
+            let solution = solver.solve_with_items(&knapsack).unwrap();
+            assert!(solution.get_total_weights().iter().zip(knapsack.get_capacities()).all(|(w, c)| w <= c));
+            if solver.get_name() == "Multi-dimensional greedy" {
+                assert!(solution.get_total_value() <= expected);
+            } else {
+                assert_eq!(solution.get_total_value(), expected, "{}", solver.get_name());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_err_on_large_capacities() {
+    let knapsack = MultiDimensionalKnapsack::new(vec![u64::MAX, 2], vec![]).unwrap();
+
+    assert_eq!(
+        MultiDimensionalAlgorithmsService::solve("Multi-dimensional dynamic".to_string(), &knapsack),
+        Err(KnapsackError::CapacityTooLarge)
+    );
+
+    // The table of values fits, but the decisions of so many items don't
+    let items = (0..70_000).map(|_| MultiDimensionalItem::new(vec![2000, 2000], 1)).collect();
+    let knapsack = MultiDimensionalKnapsack::new(vec![1023, 1023], items).unwrap();
+    assert_eq!(MultiDimensionalAlgorithmsService::solve("Multi-dimensional dynamic".to_string(), &knapsack), Ok(0));
+    assert_eq!(
+        MultiDimensionalAlgorithmsService::solve_with_items("Multi-dimensional dynamic".to_string(), &knapsack),
+        Err(KnapsackError::CapacityTooLarge)
+    );
+}
@@ -0,0 +1,74 @@
+use crate::models::item::Item;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::multiple_knapsack_solution::MultipleKnapsackSolution;
+use crate::multiple_algorithms_service::MultipleAlgorithmsService;
+use crate::tests::Random;
+
+/// Full iteration over all assignments of items to knapsacks.
+fn brute_force(knapsack: &MultipleKnapsack, index: usize, loads: &mut Vec<u64>) -> u64 {
+    if index == knapsack.get_items_len() {
+        return 0;
+    }
+    let item = knapsack.get_item(index);
+    let mut best = brute_force(knapsack, index + 1, loads);
+    for k in 0..knapsack.get_knapsacks_len() {
+        if loads[k] + item.get_weight() <= knapsack.get_capacities()[k] {
+            loads[k] += item.get_weight();
+            best = best.max(item.get_value() + brute_force(knapsack, index + 1, loads));
+            loads[k] -= item.get_weight();
+        }
+    }
+    best
+}
+
+/// Checks that the loads of the solution don't exceed the capacities.
+fn is_feasible(knapsack: &MultipleKnapsack, solution: &MultipleKnapsackSolution) -> bool {
+    solution.get_loads().iter().zip(knapsack.get_capacities()).all(|(load, capacity)| load <= capacity)
+}
+
+#[test]
+// We check the case where filling the knapsacks one by one is not optimal.
+fn test_sequential_is_not_optimal() {
+    let items = vec![Item::new(3, 3), Item::new(3, 3), Item::new(2, 2), Item::new(2, 2), Item::new(2, 2)];
+    let knapsack = MultipleKnapsack::new(vec![6, 6], items);
+
+    let solution = MultipleAlgorithmsService::solve_with_items("Multiple branch and bound".to_string(), &knapsack).unwrap();
+    assert_eq!(solution.get_total_value(), 12);
+    assert_eq!(solution.get_loads(), &[6, 6]);
+    assert!(solution.get_assignments().iter().all(|assignment| assignment.is_some()));
+}
+
+#[test]
+// We check that an item heavier than every knapsack is never assigned.
+fn test_unassigned_items() {
+    let items = vec![Item::new(10, 100), Item::new(4, 5), Item::new(5, 7)];
+    let knapsack = MultipleKnapsack::new(vec![5, 4], items);
+
+    for solver in MultipleAlgorithmsService::get_all_algorithms() {
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        assert_eq!(solution.get_assignments(), &[None, Some(1), Some(0)], "{}", solver.get_name());
+        assert_eq!(solution.get_total_value(), 12, "{}", solver.get_name());
+    }
+}
+
+#[test]
+// We check that the exact algorithm matches the full iteration and the heuristics are feasible.
+fn test_same_as_brute_force() {
+    let mut random = Random::new(8);
+
+    for _ in 0..20 {
+        let items: Vec<Item> = (0..8).map(|_| Item::new(random.next(20), random.next(100))).collect();
+        let knapsack = MultipleKnapsack::new(vec![random.next(30), random.next(30), random.next(30)], items);
+        let expected = brute_force(&knapsack, 0, &mut vec![0; 3]);
+
+        for solver in MultipleAlgorithmsService::get_all_algorithms() {
+            let solution = solver.solve_with_items(&knapsack).unwrap();
+            assert!(is_feasible(&knapsack, &solution), "{}", solver.get_name());
+            if solver.get_name() == "Multiple branch and bound" {
+                assert_eq!(solution.get_total_value(), expected);
+            } else {
+                assert!(solution.get_total_value() <= expected, "{}", solver.get_name());
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::algorithms_impls::bounds::{dantzig_bound, lagrangian_bound, martello_toth_bound, multiple_surrogate_bound, optimality_gap};
+use crate::algorithms_impls::fractional::FractionalKnapsackSolver;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::instance_features::InstanceFeatures;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::multi_dimensional_item::MultiDimensionalItem;
+use crate::models::multi_dimensional_knapsack::MultiDimensionalKnapsack;
+use crate::models::multiple_knapsack::MultipleKnapsack;
+use crate::models::solver_capabilities::Exactness;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::multi_dimensional_algorithms_service::MultiDimensionalAlgorithmsService;
+use crate::multiple_algorithms_service::MultipleAlgorithmsService;
+use crate::unbounded_algorithms_service::UnboundedAlgorithmsService;
+
+/// Checks the result of every registered algorithm supporting the knapsack against the optimum.
+///
+/// Exact algorithms must find the optimum, the others a feasible solution not better than it.
+fn check_all_algorithms(knapsack: &Knapsack, optimum: u64) {
+    for solver in AlgorithmsService::get_all_algorithms() {
+        let capabilities = solver.get_capabilities();
+        if capabilities.check(knapsack).is_err() {
+            continue;
+        }
+
+        let solution = solver.solve_with_items(knapsack).unwrap_or_else(|error| panic!("{}: {}", solver.get_name(), error));
+        assert!(solution.get_total_weight() <= knapsack.get_capacity(), "{}", solver.get_name());
+        match capabilities.get_exactness() {
+            Exactness::Exact => assert_eq!(solution.get_total_value(), optimum, "{}", solver.get_name()),
+            _ => assert!(solution.get_total_value() <= optimum, "{}", solver.get_name()),
+        }
+        assert_eq!(solver.solve(knapsack).map(|value| value <= optimum), Ok(true), "{}", solver.get_name());
+    }
+}
+
+#[test]
+// We check that sums over items with the largest counts overflow neither the check nor the features.
+fn test_counts_overflow() {
+    let knapsack = Knapsack::new(u64::MAX, vec![Item::with_count(1, u64::MAX, u64::MAX); 2]);
+    assert_eq!(knapsack.check_overflow(), Err(KnapsackError::ArithmeticOverflow));
+
+    let knapsack = Knapsack::new(u64::MAX, vec![Item::with_count(u64::MAX, 1, u64::MAX); 2]);
+    assert_eq!(InstanceFeatures::from_knapsack(&knapsack).get_weight_sum(), u128::MAX);
+}
+
+#[test]
+// We check that every algorithm reports an overflow if the total value doesn't fit in u64.
+fn test_values_overflow() {
+    let half = u64::MAX / 2 + 1;
+    let knapsack = Knapsack::new(2, vec![Item::new(1, half), Item::new(1, half)]);
+
+    assert_eq!(knapsack.check_overflow(), Err(KnapsackError::ArithmeticOverflow));
+    for solver in AlgorithmsService::get_all_algorithms() {
+        assert_eq!(solver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow), "{}", solver.get_name());
+        assert_eq!(solver.solve_with_items(&knapsack), Err(KnapsackError::ArithmeticOverflow), "{}", solver.get_name());
+    }
+
+    // Copies of bounded items are counted
+    let knapsack = Knapsack::new(2, vec![Item::with_count(1, half, 2)]);
+    assert_eq!(knapsack.check_overflow(), Err(KnapsackError::ArithmeticOverflow));
+
+    // Items which don't fit can't overflow the value
+    let knapsack = Knapsack::new(1, vec![Item::new(1, half), Item::new(2, half)]);
+    assert_eq!(knapsack.check_overflow(), Ok(()));
+}
+
+#[test]
+// We check that the algorithms find a solution with a value of exactly u64::MAX.
+fn test_values_near_max() {
+    let knapsack = Knapsack::new(3, vec![Item::new(1, u64::MAX - 1), Item::new(2, 1), Item::new(4, u64::MAX / 2)]);
+    check_all_algorithms(&knapsack, u64::MAX);
+}
+
+#[test]
+// We check that the sums of weights near u64::MAX don't overflow.
+fn test_weights_near_max() {
+    let capacity = u64::MAX - 1;
+    let knapsack = Knapsack::new(capacity, vec![Item::new(capacity - 1, 5), Item::new(2, 3), Item::new(3, 4), Item::new(u64::MAX, 100)]);
+    check_all_algorithms(&knapsack, 7);
+
+    let knapsack = Knapsack::new(u64::MAX, vec![Item::new(u64::MAX, 5), Item::new(u64::MAX - 1, 3), Item::new(1, 3)]);
+    check_all_algorithms(&knapsack, 6);
+}
+
+#[test]
+// We check that the solvers of the other problems report an overflow of the value.
+fn test_other_problems_overflow() {
+    let half = u64::MAX / 2 + 1;
+
+    let knapsack = UnboundedKnapsack::new(3, vec![Item::new(1, half)]);
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        assert_eq!(solver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow), "{}", solver.get_name());
+    }
+    let knapsack = UnboundedKnapsack::new(3, vec![Item::new(1, u64::MAX / 3)]);
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        assert_eq!(solver.solve(&knapsack), Ok(u64::MAX / 3 * 3), "{}", solver.get_name());
+    }
+
+    let items = vec![MultiDimensionalItem::new(vec![1, 1], half), MultiDimensionalItem::new(vec![1, 1], half)];
+    let knapsack = MultiDimensionalKnapsack::new(vec![2, 2], items).unwrap();
+    for solver in MultiDimensionalAlgorithmsService::get_all_algorithms() {
+        assert_eq!(solver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow), "{}", solver.get_name());
+    }
+
+    let knapsack = MultipleKnapsack::new(vec![1, 1], vec![Item::new(1, half), Item::new(1, half)]);
+    for solver in MultipleAlgorithmsService::get_all_algorithms() {
+        assert_eq!(solver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow), "{}", solver.get_name());
+    }
+}
+
+#[test]
+// We check that the fractional solver reports an overflow and the bounds saturate instead of overflowing.
+fn test_fractional_and_bounds_overflow() {
+    let large = u64::MAX / 2 + 10;
+    let items = vec![Item::new(1, large), Item::new(1, large), Item::new(2, 5)];
+    let knapsack = Knapsack::new(3, items.clone());
+
+    assert_eq!(FractionalKnapsackSolver.solve(&knapsack), Err(KnapsackError::ArithmeticOverflow));
+    assert_eq!(AlgorithmsService::solve_fractional(&knapsack), Err(KnapsackError::ArithmeticOverflow));
+    assert_eq!(dantzig_bound(&knapsack), u64::MAX);
+    assert_eq!(martello_toth_bound(&knapsack), u64::MAX);
+    assert_eq!(lagrangian_bound(&knapsack, (0, 1)), Ok(u64::MAX));
+    assert_eq!(optimality_gap(&knapsack, 0), 1.0);
+    assert_eq!(multiple_surrogate_bound(&MultipleKnapsack::new(vec![2, 1], items)), u64::MAX);
+
+    // The critical item is heavier than the capacity, so it isn't checked, but it doesn't overflow the bound
+    let knapsack = Knapsack::new(3, vec![Item::new(1, u64::MAX - 1), Item::new(2, 1), Item::new(4, u64::MAX)]);
+    let solution = FractionalKnapsackSolver.solve(&knapsack).unwrap();
+    assert_eq!(solution.get_integral_value(), u64::MAX - 1);
+    assert_eq!(solution.get_critical_item(), Some(2));
+    assert_eq!(solution.get_upper_bound(), u64::MAX);
+    assert_eq!(martello_toth_bound(&knapsack), u64::MAX);
+}
@@ -0,0 +1,47 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::parallel_dynamic::ParallelDynamicKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+// We check that the results and the selected items are identical to the sequential version for any number of threads.
+fn test_same_as_sequential() {
+    let mut random = Random::new(16);
+
+    for round in 0..6 {
+        // Large capacities split the rows into several chunks, small ones are filled by a single thread
+        let capacity = if round % 2 == 0 { random.next(200_000) + 50_000 } else { random.next(1000) };
+        let items: Vec<Item> = (0..20).map(|_| Item::new(random.next(capacity / 4), random.next(1000))).collect();
+        let knapsack = Knapsack::new(capacity, items);
+
+        let expected = DynamicKnapsackSolver.solve_with_items(&knapsack).unwrap();
+        for threads in [1, 2, 3, 8] {
+            let solver = ParallelDynamicKnapsackSolver::new(threads);
+            assert_eq!(solver.solve_with_items(&knapsack).unwrap(), expected, "{}", solver.get_name());
+            assert_eq!(solver.solve(&knapsack), Ok(expected.get_total_value()), "{}", solver.get_name());
+        }
+    }
+}
+
+#[test]
+// We check that a solver without threads is reported.
+fn test_err_on_zero_threads() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10)]);
+
+    assert_eq!(
+        ParallelDynamicKnapsackSolver::new(0).solve(&knapsack),
+        Err(KnapsackError::InvalidParameter("the number of threads must be positive".to_string()))
+    );
+    assert_eq!(ParallelDynamicKnapsackSolver::new(4).get_id(), "parallel_dynamic_4");
+}
+
+#[test]
+// We check that the bitsets of decisions are bounded even if the capacity is below the limit.
+fn test_err_on_large_decisions() {
+    let knapsack = Knapsack::new(1_000_000_000, vec![Item::new(20_000_000, 1); 100]);
+
+    assert_eq!(ParallelDynamicKnapsackSolver::new(4).solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+}
@@ -0,0 +1,116 @@
+use crate::algorithms_impls::bounded_dynamic::BoundedDynamicKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::preprocessed::PreprocessedKnapsackSolver;
+use crate::algorithms_impls::preprocessing::Preprocessor;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+/// A preprocessor with only the given reductions enabled.
+fn only(dominance: bool, variable_fixing: bool, weight_gcd: bool) -> Preprocessor {
+    Preprocessor::new()
+        .with_dominance(dominance)
+        .with_variable_fixing(variable_fixing)
+        .with_weight_gcd(weight_gcd)
+}
+
+#[test]
+// We check that useless items are removed and free items are always taken.
+fn test_useless_items() {
+    let knapsack = Knapsack::new(10, vec![Item::new(11, 100), Item::new(0, 5), Item::new(3, 0), Item::new(4, 6)]);
+    let reduced = only(false, false, false).reduce(&knapsack);
+
+    assert_eq!(reduced.get_original_indices(), &[3]);
+    assert_eq!(reduced.get_fixed_items(), &[1]);
+    assert_eq!(reduced.get_fixed_value(), 5);
+    assert_eq!(reduced.get_knapsack().get_capacity(), 10);
+}
+
+#[test]
+// We check that an item is removed only if it can't be taken together with the items dominating it.
+fn test_dominance() {
+    // Items 1 and 2 dominate item 0, but only item 2 dominates item 1
+    let knapsack = Knapsack::new(10, vec![Item::new(6, 5), Item::new(5, 9), Item::new(4, 9)]);
+    let reduced = only(true, false, false).reduce(&knapsack);
+    assert_eq!(reduced.get_original_indices(), &[1, 2]);
+
+    // Item 0 can be taken together with both items dominating it
+    let knapsack = Knapsack::new(15, vec![Item::new(6, 5), Item::new(5, 9), Item::new(4, 9)]);
+    let reduced = only(true, false, false).reduce(&knapsack);
+    assert_eq!(reduced.get_original_indices(), &[0, 1, 2]);
+
+    // Of two equal items that don't fit together, one is kept
+    let knapsack = Knapsack::new(10, vec![Item::new(6, 5), Item::new(6, 5)]);
+    let reduced = only(true, false, false).reduce(&knapsack);
+    assert_eq!(reduced.get_original_indices(), &[0]);
+}
+
+#[test]
+// We check that the bounds force items in and out of the knapsack.
+fn test_variable_fixing() {
+    // The greedy solution takes items 0 and 1 with value 40, without item 0 the bound is 15 and without item 1 it is 35
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 30), Item::new(5, 10), Item::new(6, 6), Item::new(4, 2)]);
+    let reduced = only(false, true, false).reduce(&knapsack);
+
+    assert_eq!(reduced.get_fixed_items(), &[0, 1]);
+    assert_eq!(reduced.get_fixed_value(), 40);
+    assert!(reduced.get_original_indices().is_empty());
+
+    // Items 1 and 2 have bounds of 42 both when taken and skipped, item 3 has the bound of 35 when taken
+    let knapsack = Knapsack::new(11, vec![Item::new(5, 30), Item::new(5, 10), Item::new(6, 12), Item::new(4, 1)]);
+    let reduced = only(false, true, false).reduce(&knapsack);
+
+    assert_eq!(reduced.get_fixed_items(), &[0]);
+    assert_eq!(reduced.get_original_indices(), &[1, 2]);
+    assert_eq!(reduced.get_knapsack().get_capacity(), 6);
+}
+
+#[test]
+// We check that the common divisor of the weights is divided out.
+fn test_weight_gcd() {
+    let knapsack = Knapsack::new(20, vec![Item::new(6, 5), Item::new(9, 7), Item::new(12, 8)]);
+    let reduced = only(false, false, true).reduce(&knapsack);
+
+    assert_eq!(reduced.get_weight_divisor(), 3);
+    assert_eq!(reduced.get_knapsack().get_capacity(), 6);
+    assert_eq!(reduced.get_knapsack().get_item(2).get_weight(), 4);
+}
+
+#[test]
+// We check that the reduced knapsacks keep the optimum and the solutions map back to valid ones.
+fn test_same_as_dynamic() {
+    let mut random = Random::new(18);
+
+    for round in 0..60 {
+        let scale = if round % 3 == 0 { 4 } else { 1 };
+        let items: Vec<Item> = (0..15).map(|_| Item::new(random.next(40) * scale, random.next(60) - 1)).collect();
+        let knapsack = Knapsack::new(random.next(200) * scale, items);
+        let expected = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        for mask in 0..8 {
+            let preprocessor = only(mask & 1 != 0, mask & 2 != 0, mask & 4 != 0);
+            let solver = PreprocessedKnapsackSolver::with_preprocessor(Box::new(DynamicKnapsackSolver), preprocessor);
+            let solution = solver.solve_with_items(&knapsack).unwrap();
+            assert_eq!(solution.get_total_value(), expected, "{} {}", round, mask);
+            assert!(solution.get_total_weight() <= knapsack.get_capacity());
+            assert_eq!(solver.solve(&knapsack), Ok(expected));
+        }
+    }
+}
+
+#[test]
+// We check that bounded knapsacks are only reduced in ways that keep the copies correct.
+fn test_bounded() {
+    let knapsack = Knapsack::new(
+        12,
+        vec![Item::with_count(4, 5, 3), Item::with_count(2, 2, 2), Item::with_count(14, 50, 1), Item::with_count(6, 9, 1)],
+    );
+    let expected = BoundedDynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+    let solver = PreprocessedKnapsackSolver::new(Box::new(BoundedDynamicKnapsackSolver));
+    let solution = solver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_total_value(), expected);
+    assert!(solution.get_total_weight() <= 12);
+    assert_eq!(solver.get_id(), "preprocessed_bounded_dynamic");
+}
@@ -0,0 +1,61 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::profit_dynamic::ProfitDynamicKnapsackSolver;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+// We check a capacity which is far too large for the dynamic programming over weights.
+fn test_huge_capacity() {
+    let items = vec![
+        Item::new(400_000_000_000, 30),
+        Item::new(350_000_000_000, 25),
+        Item::new(300_000_000_000, 20),
+        Item::new(250_000_000_000, 18),
+        Item::new(2_000_000_000_000, 100),
+    ];
+    let knapsack = Knapsack::new(1_000_000_000_000, items);
+
+    assert!(ProfitDynamicKnapsackSolver::is_preferred(&knapsack));
+
+    let solution = ProfitDynamicKnapsackSolver.solve_with_items(&knapsack).unwrap();
+    assert_eq!(solution.get_items(), &[0, 1, 3]);
+    assert_eq!(solution.get_total_weight(), 1_000_000_000_000);
+    assert_eq!(solution.get_total_value(), 73);
+    assert_eq!(ProfitDynamicKnapsackSolver.solve(&knapsack), Ok(73));
+}
+
+#[test]
+// We check that the solver isn't preferred when the values are large and the capacity small.
+fn test_not_preferred() {
+    let items = vec![Item::new(3, 1_000_000), Item::new(4, 2_000_000), Item::new(100, 5_000_000)];
+    let knapsack = Knapsack::new(10, items);
+
+    assert!(!ProfitDynamicKnapsackSolver::is_preferred(&knapsack));
+    assert_eq!(ProfitDynamicKnapsackSolver.solve(&knapsack), Ok(3_000_000));
+}
+
+#[test]
+// We check that the bitsets of decisions are bounded even if the sum of the values is below the limit.
+fn test_err_on_large_decisions() {
+    let knapsack = Knapsack::new(100, vec![Item::new(1, 10_000_000); 100]);
+
+    assert_eq!(ProfitDynamicKnapsackSolver.solve_with_items(&knapsack), Err(KnapsackError::CapacityTooLarge));
+}
+
+#[test]
+// We check that the solver matches the exact solvers on random knapsacks with large weights.
+fn test_same_as_branch_and_bound() {
+    let mut random = Random::new(10);
+
+    for _ in 0..30 {
+        let items: Vec<Item> = (0..25).map(|_| Item::new(random.next(1 << 30) * 1000, random.next(100))).collect();
+        let knapsack = Knapsack::new(random.next(1 << 30) * 5000, items);
+
+        let solution = ProfitDynamicKnapsackSolver.solve_with_items(&knapsack).unwrap();
+        assert!(solution.get_total_weight() <= knapsack.get_capacity());
+        assert_eq!(solution.get_total_value(), BranchAndBoundKnapsackSolver.solve(&knapsack).unwrap());
+    }
+}
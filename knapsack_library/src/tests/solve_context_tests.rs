@@ -0,0 +1,104 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_recursion::RecursiveKnapsackSolver;
+use crate::algorithms_impls::greedy::GreedyKnapsackSolver;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::{CancellationToken, SolveContext};
+use crate::tests::Random;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A knapsack with 50 items, which none of the solvers keeping an incumbent can finish quickly.
+fn large_knapsack() -> Knapsack {
+    let mut random = Random::new(5);
+    let items = (0..50)
+        .map(|_| {
+            let weight = random.next(1000) * 2;
+            // Equal ratios and even weights with an odd capacity: the bounds never prune a subtree
+            Item::new(weight, weight)
+        })
+        .collect();
+    Knapsack::new(12345, items)
+}
+
+#[test]
+// We check that a context without a deadline and a token gives the proven optimum.
+fn test_unlimited_context() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10), Item::new(4, 40), Item::new(6, 30), Item::new(3, 50)]);
+    let solvers: Vec<Box<dyn KnapsackSolver>> =
+        vec![Box::new(RecursiveKnapsackSolver), Box::new(BitMaskKnapsackSolver), Box::new(BranchAndBoundKnapsackSolver)];
+
+    for solver in solvers {
+        let outcome = solver.solve_with_context(&knapsack, &SolveContext::new()).unwrap();
+        assert!(outcome.is_proven());
+        assert_eq!(outcome.get_solution().get_total_value(), 90);
+    }
+}
+
+#[test]
+// We check that the solvers keeping an incumbent stop at the deadline and return a feasible solution which isn't proven.
+fn test_deadline() {
+    let knapsack = large_knapsack();
+    let solvers: Vec<Box<dyn KnapsackSolver>> =
+        vec![Box::new(RecursiveKnapsackSolver), Box::new(BitMaskKnapsackSolver), Box::new(BranchAndBoundKnapsackSolver)];
+
+    for solver in solvers {
+        let start = Instant::now();
+        let context = SolveContext::new().with_time_limit(Duration::from_millis(100));
+        let outcome = solver.solve_with_context(&knapsack, &context).unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(!outcome.is_proven());
+        assert!(outcome.get_solution().get_total_weight() <= knapsack.get_capacity());
+    }
+}
+
+#[test]
+// We check that branch and bound returns at least the greedy solution when stopped.
+fn test_branch_and_bound_keeps_greedy_solution() {
+    let knapsack = large_knapsack();
+    let context = SolveContext::new().with_deadline(Instant::now());
+    let outcome = BranchAndBoundKnapsackSolver.solve_with_context(&knapsack, &context).unwrap();
+
+    assert!(!outcome.is_proven());
+    assert!(outcome.get_solution().get_total_value() >= GreedyKnapsackSolver.solve(&knapsack).unwrap());
+}
+
+#[test]
+// We check that a solver can be cancelled from another thread.
+fn test_cancellation_token() {
+    let knapsack = large_knapsack();
+    let token = CancellationToken::new();
+    let context = SolveContext::new().with_cancellation_token(token.clone());
+
+    let outcome = thread::scope(|scope| {
+        let handle = scope.spawn(|| RecursiveKnapsackSolver.solve_with_context(&knapsack, &context));
+        thread::sleep(Duration::from_millis(50));
+        token.cancel();
+        handle.join().unwrap()
+    });
+
+    assert!(token.is_cancelled());
+    assert!(!outcome.unwrap().is_proven());
+}
+
+#[test]
+// We check that solvers without an incumbent refuse to start with a stopped context.
+fn test_stopped_context_without_incumbent() {
+    let knapsack = Knapsack::new(10, vec![Item::new(5, 10)]);
+    let token = CancellationToken::new();
+    token.cancel();
+    let context = SolveContext::new().with_cancellation_token(token);
+
+    assert_eq!(DynamicKnapsackSolver.solve_with_context(&knapsack, &context), Err(KnapsackError::Cancelled));
+    assert_eq!(
+        AlgorithmsService::solve_with_context("Dynamic".to_string(), &knapsack, &context),
+        Err(KnapsackError::Cancelled)
+    );
+    assert!(!RecursiveKnapsackSolver.solve_with_context(&knapsack, &context).unwrap().is_proven());
+}
@@ -0,0 +1,109 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_recursion::RecursiveKnapsackSolver;
+use crate::algorithms_impls::lazy_dynamic::LazyDynamicKnapsackSolver;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solve_context::SolveContext;
+use crate::models::solve_observer::SolveEvent;
+use std::sync::{Arc, Mutex};
+
+/// Solves the knapsack with the solver and collects the reported events.
+fn collect_events(solver: &dyn KnapsackSolver, knapsack: &Knapsack) -> Vec<SolveEvent> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    let context = SolveContext::new().with_observer(Arc::new(move |event| sink.lock().unwrap().push(event)));
+
+    let outcome = solver.solve_with_context(knapsack, &context).unwrap();
+    assert!(outcome.is_proven());
+    let events = events.lock().unwrap().clone();
+    events
+}
+
+/// The values of the incumbents in the order they were reported.
+fn incumbents(events: &[SolveEvent]) -> Vec<u64> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            SolveEvent::IncumbentFound { value } => Some(*value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A knapsack with 16 items, so that the full iteration visits enough nodes to report them.
+fn knapsack() -> Knapsack {
+    let items = (1..=16).map(|i| Item::new(i * 3 % 17 + 1, i * 5 % 23 + 1)).collect();
+    Knapsack::new(40, items)
+}
+
+#[test]
+// We check that the incumbents of the searching solvers increase and end with the optimum.
+fn test_incumbents() {
+    let knapsack = knapsack();
+    let optimum = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+    let solvers: Vec<Box<dyn KnapsackSolver>> =
+        vec![Box::new(RecursiveKnapsackSolver), Box::new(BitMaskKnapsackSolver), Box::new(BranchAndBoundKnapsackSolver)];
+
+    for solver in solvers {
+        let values = incumbents(&collect_events(solver.as_ref(), &knapsack));
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(values.last(), Some(&optimum));
+    }
+}
+
+#[test]
+// We check that the full iteration reports the number of explored nodes.
+fn test_nodes_explored() {
+    let events = collect_events(&BitMaskKnapsackSolver, &knapsack());
+    let nodes: Vec<u64> = events
+        .iter()
+        .filter_map(|event| match event {
+            SolveEvent::NodesExplored { nodes } => Some(*nodes),
+            _ => None,
+        })
+        .collect();
+
+    // 2^16 combinations are checked
+    assert_eq!(nodes.len(), 16);
+    assert_eq!(nodes.last(), Some(&(1 << 16)));
+
+    let events = collect_events(&LazyDynamicKnapsackSolver, &Knapsack::new(5000, (1..=40).map(|i| Item::new(i * 7, i)).collect()));
+    assert!(events.iter().any(|event| matches!(event, SolveEvent::NodesExplored { .. })));
+}
+
+#[test]
+// We check that the dynamic programming reports every row and its incumbents end with the optimum.
+fn test_dynamic_rows() {
+    let knapsack = knapsack();
+    let events = collect_events(&DynamicKnapsackSolver, &knapsack);
+    let rows: Vec<(usize, usize)> = events
+        .iter()
+        .filter_map(|event| match event {
+            SolveEvent::RowCompleted { row, rows } => Some((*row, *rows)),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(rows, (1..=16).map(|row| (row, 16)).collect::<Vec<_>>());
+    assert_eq!(incumbents(&events).last(), Some(&DynamicKnapsackSolver.solve(&knapsack).unwrap()));
+}
+
+#[test]
+// We check that the service passes the observer to the algorithm.
+fn test_service_observer() {
+    let rows = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&rows);
+    let observer = Arc::new(move |event| {
+        if let SolveEvent::RowCompleted { .. } = event {
+            *counter.lock().unwrap() += 1;
+        }
+    });
+
+    let solution = AlgorithmsService::solve_with_observer("Dynamic".to_string(), &knapsack(), observer).unwrap();
+    assert_eq!(solution.get_total_value(), DynamicKnapsackSolver.solve(&knapsack()).unwrap());
+    assert_eq!(*rows.lock().unwrap(), 16);
+}
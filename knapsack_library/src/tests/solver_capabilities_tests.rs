@@ -0,0 +1,89 @@
+use crate::algorithms_impls::branch_and_bound::BranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::fptas::FptasKnapsackSolver;
+use crate::algorithms_impls::full_iteration_with_bit_mask::BitMaskKnapsackSolver;
+use crate::algorithms_impls::profit_dynamic::ProfitDynamicKnapsackSolver;
+use crate::algorithms_impls::subset_sum::SubsetSumKnapsackSolver;
+use crate::algorithms_registry::AlgorithmsRegistry;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::models::solver_capabilities::{Exactness, SolverCapabilities};
+use crate::tests::Random;
+
+#[test]
+// We check the size limits reported for knapsacks the solvers can't handle.
+fn test_check_limits() {
+    let items: Vec<Item> = (1..=66).map(|i| Item::new(i, i)).collect();
+    let many_items = Knapsack::new(100, items);
+    assert_eq!(
+        BitMaskKnapsackSolver.get_capabilities().check(&many_items),
+        Err(KnapsackError::TooManyItems { items: 66, max: 63 })
+    );
+    assert_eq!(BranchAndBoundKnapsackSolver.get_capabilities().check(&many_items), Ok(()));
+
+    let huge_capacity = Knapsack::new(1_000_000_000_000, vec![Item::new(1, 1)]);
+    assert_eq!(DynamicKnapsackSolver.get_capabilities().check(&huge_capacity), Err(KnapsackError::CapacityTooLarge));
+    assert_eq!(ProfitDynamicKnapsackSolver.get_capabilities().check(&huge_capacity), Ok(()));
+
+    let huge_values = Knapsack::new(10, vec![Item::new(1, u64::MAX), Item::new(1, u64::MAX)]);
+    assert_eq!(ProfitDynamicKnapsackSolver.get_capabilities().check(&huge_values), Err(KnapsackError::CapacityTooLarge));
+    // An item heavier than the capacity is never taken, so its value doesn't count
+    let heavy_value = Knapsack::new(10, vec![Item::new(1, 5), Item::new(11, u64::MAX)]);
+    assert_eq!(ProfitDynamicKnapsackSolver.get_capabilities().check(&heavy_value), Ok(()));
+    let many_copies = Knapsack::new(10, vec![Item::with_count(1, 1 << 29, 4)]);
+    assert_eq!(ProfitDynamicKnapsackSolver.get_capabilities().check(&many_copies), Err(KnapsackError::CapacityTooLarge));
+
+    let capabilities = SubsetSumKnapsackSolver.get_capabilities();
+    assert!(capabilities.requires_proportional_values());
+    assert!(!DynamicKnapsackSolver.get_capabilities().requires_proportional_values());
+    assert_eq!(capabilities.check(&Knapsack::new(10, vec![Item::new(2, 4), Item::new(3, 6)])), Ok(()));
+    assert_eq!(
+        capabilities.check(&Knapsack::new(10, vec![Item::new(2, 4), Item::new(3, 5)])),
+        Err(KnapsackError::InvalidInstance("the values are not proportional to the weights".to_string()))
+    );
+}
+
+#[test]
+// We check the exactness metadata and the defaults for solvers without metadata.
+fn test_exactness() {
+    let capabilities = FptasKnapsackSolver::new(0.1).get_capabilities();
+    assert_eq!(capabilities.get_exactness(), Exactness::Approximate(0.9));
+    assert!(!capabilities.is_exact());
+    assert_eq!(capabilities.get_time_complexity(), "O(n^2 / ε)");
+
+    assert_eq!(DynamicKnapsackSolver.get_capabilities().get_approximation_ratio(), Some(1.0));
+
+    let unknown = SolverCapabilities::unknown();
+    assert_eq!(unknown.get_approximation_ratio(), None);
+    assert!(!unknown.returns_items());
+}
+
+#[test]
+// We check that the solvers keep the promises of their metadata on supported knapsacks.
+fn test_promises_kept() {
+    let mut random = Random::new(14);
+
+    for _ in 0..20 {
+        let items: Vec<Item> = (0..15).map(|_| Item::new(random.next(50), random.next(100))).collect();
+        let knapsack = Knapsack::new(random.next(300), items);
+        let optimum = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        for solver in AlgorithmsRegistry::with_builtin_algorithms().get_all_algorithms() {
+            let capabilities = solver.get_capabilities();
+            // Random values are almost never proportional to the weights
+            if capabilities.requires_proportional_values() && !knapsack.has_proportional_values() {
+                assert!(capabilities.check(&knapsack).is_err(), "{}", solver.get_name());
+                continue;
+            }
+            assert_eq!(capabilities.check(&knapsack), Ok(()), "{}", solver.get_name());
+
+            let solution = solver.solve_with_items(&knapsack).unwrap();
+            assert!(capabilities.returns_items(), "{}", solver.get_name());
+            if let Some(ratio) = capabilities.get_approximation_ratio() {
+                assert!(solution.get_total_value() as f64 >= ratio * optimum as f64, "{}", solver.get_name());
+            }
+        }
+    }
+}
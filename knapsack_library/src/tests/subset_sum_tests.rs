@@ -0,0 +1,67 @@
+use crate::algorithms_impls::dynamic::DynamicKnapsackSolver;
+use crate::algorithms_impls::subset_sum::SubsetSumKnapsackSolver;
+use crate::algorithms_service::AlgorithmsService;
+use crate::models::auto_decision::AutoChoice;
+use crate::models::item::Item;
+use crate::models::knapsack::Knapsack;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::KnapsackSolver;
+use crate::tests::Random;
+
+#[test]
+// We check the detection of knapsacks with values proportional to weights.
+fn test_is_subset_sum() {
+    assert!(SubsetSumKnapsackSolver::is_subset_sum(&Knapsack::new(10, vec![Item::new(3, 3), Item::new(4, 4)])));
+    assert!(SubsetSumKnapsackSolver::is_subset_sum(&Knapsack::new(10, vec![Item::new(2, 3), Item::new(4, 6), Item::new(0, 0)])));
+    // Items that don't fit don't matter
+    assert!(SubsetSumKnapsackSolver::is_subset_sum(&Knapsack::new(10, vec![Item::new(3, 3), Item::new(11, 1)])));
+    assert!(!SubsetSumKnapsackSolver::is_subset_sum(&Knapsack::new(10, vec![Item::new(3, 3), Item::new(4, 5)])));
+    assert!(!SubsetSumKnapsackSolver::is_subset_sum(&Knapsack::new(10, vec![Item::new(3, 3), Item::new(0, 1)])));
+    // The check lives in the model, so that the instance features don't depend on the solver
+    assert!(!Knapsack::new(10, vec![Item::new(3, 3), Item::new(4, 5)]).has_proportional_values());
+    assert!(Knapsack::new(10, vec![Item::new(3, 6), Item::new(4, 8)]).has_proportional_values());
+
+    assert_eq!(
+        SubsetSumKnapsackSolver.solve(&Knapsack::new(10, vec![Item::new(3, 3), Item::new(4, 5)])),
+        Err(KnapsackError::InvalidInstance("the values are not proportional to the weights".to_string()))
+    );
+}
+
+#[test]
+// We check that the bitset gives the optimal value and valid items, also across word boundaries.
+fn test_same_as_dynamic() {
+    let mut random = Random::new(17);
+
+    for round in 0..30 {
+        let ratio = if round % 3 == 0 { 1 } else { random.next(5) };
+        let items: Vec<Item> = (0..12)
+            .map(|_| {
+                let weight = random.next(if round % 2 == 0 { 70 } else { 500 });
+                Item::new(weight, weight * ratio)
+            })
+            .collect();
+        let knapsack = Knapsack::new(random.next(2000), items);
+        let expected = DynamicKnapsackSolver.solve(&knapsack).unwrap();
+
+        let solution = SubsetSumKnapsackSolver.solve_with_items(&knapsack).unwrap();
+        assert_eq!(solution.get_total_value(), expected);
+        assert!(solution.get_total_weight() <= knapsack.get_capacity());
+        let mut items = solution.get_items().to_vec();
+        items.dedup();
+        assert_eq!(items.len(), solution.get_items().len());
+        assert_eq!(SubsetSumKnapsackSolver.solve(&knapsack), Ok(expected));
+    }
+}
+
+#[test]
+// We check that the service routes subset-sum knapsacks to the bitset solver.
+fn test_service_routes_subset_sum() {
+    let knapsack = Knapsack::new(100, (1..=20).map(|w| Item::new(w * 7, w * 7)).collect());
+
+    let (solution, decision) = AlgorithmsService::solve_auto(&knapsack).unwrap();
+    assert_eq!(decision.get_choice(), AutoChoice::SubsetSum);
+    assert_eq!(solution.get_total_value(), 98);
+    assert_eq!(AlgorithmsService::solve("auto".to_string(), &knapsack), Ok(98));
+    assert_eq!(AlgorithmsService::solve("subset_sum".to_string(), &knapsack), Ok(98));
+    assert_eq!(AlgorithmsService::solve("Subset sum".to_string(), &knapsack), Ok(98));
+}
@@ -0,0 +1,126 @@
+use crate::models::item::Item;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::tests::Random;
+use crate::unbounded_algorithms_service::UnboundedAlgorithmsService;
+
+/// Straightforward dynamic programming without any reductions.
+fn naive(knapsack: &UnboundedKnapsack) -> Option<u64> {
+    let capacity = knapsack.get_capacity() as usize;
+    let mut best: Vec<Option<u64>> = vec![None; capacity + 1];
+    best[0] = Some(0);
+    for w in 1..=capacity {
+        for i in 0..knapsack.get_items_len() {
+            let item = knapsack.get_item(i);
+            let weight = item.get_weight() as usize;
+            if weight <= w {
+                if let Some(previous) = best[w - weight] {
+                    best[w] = best[w].max(Some(previous + item.get_value()));
+                }
+            }
+        }
+    }
+    if knapsack.is_exact_fill() {
+        best[capacity]
+    } else {
+        best.into_iter().flatten().max()
+    }
+}
+
+#[test]
+// We check that the algorithm takes several copies of the same item.
+fn test_unbounded_copies() {
+    let knapsack = UnboundedKnapsack::new(10, vec![Item::new(3, 5), Item::new(4, 7), Item::new(6, 11)]);
+
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        assert_eq!(solution.get_total_value(), 18);
+        assert!(solution.get_total_weight() <= 10);
+        assert_eq!(solver.solve(&knapsack), Ok(18));
+    }
+}
+
+#[test]
+// We check that the exact fill option only accepts combinations with the exact capacity.
+fn test_exact_fill() {
+    let items = vec![Item::new(2, 1), Item::new(5, 1), Item::new(4, 10)];
+    let knapsack = UnboundedKnapsack::new_exact_fill(7, items.clone());
+
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        assert_eq!(solution.get_total_weight(), 7);
+        assert_eq!(solution.get_total_value(), 2);
+    }
+
+    let knapsack = UnboundedKnapsack::new_exact_fill(3, items);
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        assert_eq!(
+            solver.solve(&knapsack),
+            Err(KnapsackError::Infeasible)
+        );
+    }
+}
+
+#[test]
+// We check that a free item with a positive value is reported instead of looping forever.
+fn test_err_on_zero_weight() {
+    let knapsack = UnboundedKnapsack::new(10, vec![Item::new(0, 1), Item::new(3, 5)]);
+
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        assert_eq!(
+            solver.solve(&knapsack),
+            Err(KnapsackError::InvalidInstance(
+                "an item with zero weight and positive value makes the value unbounded".to_string()
+            ))
+        );
+    }
+}
+
+#[test]
+// We check that the periodicity reduction allows capacities the table can't be allocated for.
+fn test_huge_capacity() {
+    let knapsack = UnboundedKnapsack::new(1_000_000_000_002, vec![Item::new(3, 5), Item::new(4, 7)]);
+
+    for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+        let solution = solver.solve_with_items(&knapsack).unwrap();
+        assert_eq!(solution.get_total_value(), 1_750_000_000_003);
+        assert_eq!(solution.get_counts(), &[2, 249_999_999_999]);
+    }
+}
+
+#[test]
+// We check that the algorithms match the dynamic programming without reductions.
+fn test_same_as_naive() {
+    let mut random = Random::new(3);
+
+    for round in 0..30 {
+        let items: Vec<Item> = (0..6).map(|_| Item::new(random.next(30), random.next(50))).collect();
+        let capacity = random.next(300);
+        let knapsack = if round % 2 == 0 {
+            UnboundedKnapsack::new(capacity, items)
+        } else {
+            UnboundedKnapsack::new_exact_fill(capacity, items)
+        };
+        let expected = naive(&knapsack);
+
+        for solver in UnboundedAlgorithmsService::get_all_algorithms() {
+            assert_eq!(solver.solve(&knapsack).ok(), expected, "{}", solver.get_name());
+        }
+    }
+}
+
+#[test]
+// We check that the service finds the algorithm by name.
+fn test_service_solve() {
+    let knapsack = UnboundedKnapsack::new(10, vec![Item::new(3, 5)]);
+
+    assert_eq!(UnboundedAlgorithmsService::solve("Unbounded dynamic".to_string(), &knapsack), Ok(15));
+    assert_eq!(
+        UnboundedAlgorithmsService::solve("Dynamic".to_string(), &knapsack),
+        Err(KnapsackError::UnknownAlgorithm {
+            name: "Dynamic".to_string(),
+            valid_names: vec!["Unbounded dynamic".to_string(), "Unbounded branch and bound".to_string()],
+        })
+    );
+    assert_eq!(UnboundedAlgorithmsService::get_algorithms_by_names(vec!["Unbounded branch and bound".to_string()]).unwrap().len(), 1);
+}
@@ -0,0 +1,116 @@
+use crate::algorithms_impls::unbounded_branch_and_bound::UnboundedBranchAndBoundKnapsackSolver;
+use crate::algorithms_impls::unbounded_dynamic::UnboundedDynamicKnapsackSolver;
+use crate::algorithms_registry::resolve_name;
+use crate::models::knapsack_error::KnapsackError;
+use crate::models::knapsack_solver::name_to_id;
+use crate::models::unbounded_knapsack::UnboundedKnapsack;
+use crate::models::unbounded_knapsack_solution::UnboundedKnapsackSolution;
+use crate::models::unbounded_knapsack_solver::UnboundedKnapsackSolver;
+
+/// A service that manages unbounded knapsack solving algorithms.
+///
+/// This service mirrors `AlgorithmsService` for the unbounded knapsack problem, where every
+/// item type can be taken any number of times.
+pub struct UnboundedAlgorithmsService;
+
+impl UnboundedAlgorithmsService {
+    /// Lowercase aliases of the algorithms with the identifiers they refer to.
+    const ALIASES: [(&'static str, &'static str); 2] = [
+        ("dp", "unbounded_dynamic"),
+        ("bnb", "unbounded_branch_and_bound"),
+    ];
+
+    /// Returns a list of all available unbounded knapsack-solving algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn UnboundedKnapsackSolver>>` containing boxed instances of all available algorithms.
+    pub fn get_all_algorithms() -> Vec<Box<dyn UnboundedKnapsackSolver>> {
+        vec![
+            Box::new(UnboundedDynamicKnapsackSolver),
+            Box::new(UnboundedBranchAndBoundKnapsackSolver),
+        ]
+    }
+
+    /// Returns the unbounded knapsack-solving algorithm with the given identifier, name or alias.
+    ///
+    /// The name is matched ignoring case, like in `AlgorithmsService`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier (e.g. "unbounded_dynamic"), name or alias (e.g. "dp") of the algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The algorithm, or `KnapsackError::UnknownAlgorithm` listing the valid names.
+    pub fn get_algorithm(name: &str) -> Result<Box<dyn UnboundedKnapsackSolver>, KnapsackError> {
+        let mut algorithms = UnboundedAlgorithmsService::get_all_algorithms();
+        let ids_and_names: Vec<(String, String)> = algorithms
+            .iter()
+            .map(|algorithm| (name_to_id(&algorithm.get_name()), algorithm.get_name()))
+            .collect();
+        let position = resolve_name(name, &ids_and_names, &UnboundedAlgorithmsService::ALIASES)?;
+        Ok(algorithms.swap_remove(position))
+    }
+
+    /// Returns a list of unbounded knapsack-solving algorithms which matches string names.
+    ///
+    /// Every name may be an identifier, a display name or an alias, and is matched ignoring case.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Box<dyn UnboundedKnapsackSolver>>` in the order of the names, without duplicates,
+    /// or `KnapsackError::UnknownAlgorithm` listing the valid names if any of the names is unknown.
+    pub fn get_algorithms_by_names(algorithm_names: Vec<String>) -> Result<Vec<Box<dyn UnboundedKnapsackSolver>>, KnapsackError> {
+        let mut algorithms: Vec<Box<dyn UnboundedKnapsackSolver>> = Vec::with_capacity(algorithm_names.len());
+        for name in &algorithm_names {
+            let algorithm = UnboundedAlgorithmsService::get_algorithm(name)?;
+            if !algorithms.iter().any(|other| other.get_name() == algorithm.get_name()) {
+                algorithms.push(algorithm);
+            }
+        }
+        Ok(algorithms)
+    }
+
+    /// Solves the unbounded knapsack problem using the specified algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `UnboundedKnapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<u64, KnapsackError>` with the maximum value, or an error if the algorithm
+    /// with the given name is not found or fails.
+    pub fn solve(name: String, knapsack: &UnboundedKnapsack) -> Result<u64, KnapsackError> {
+        UnboundedAlgorithmsService::get_algorithm(&name)?.solve(knapsack)
+    }
+
+    /// Solves the unbounded knapsack problem using the specified algorithm and returns the selected items.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The identifier, name or alias of the algorithm to use for solving the knapsack problem.
+    /// * `knapsack` - A reference to the `UnboundedKnapsack` instance to solve.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<UnboundedKnapsackSolution, KnapsackError>` with the number of copies of every item type,
+    /// or an error if the algorithm with the given name is not found or fails.
+    pub fn solve_with_items(name: String, knapsack: &UnboundedKnapsack) -> Result<UnboundedKnapsackSolution, KnapsackError> {
+        UnboundedAlgorithmsService::get_algorithm(&name)?.solve_with_items(knapsack)
+    }
+
+    /// Returns the names of all available unbounded algorithms.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` containing the names of all available algorithms.
+    pub fn get_algorithms_names() -> Vec<String> {
+        UnboundedAlgorithmsService::get_all_algorithms()
+            .into_iter()
+            .map(|algorithm| algorithm.get_name())
+            .collect()
+    }
+}